@@ -0,0 +1,41 @@
+// Arithmetic-to-boolean conversion: bit decomposition of additive FE shares
+// into XOR-shared bits, so that an input shared once over FE can feed both
+// `lt_const` and the bitwise protocols such as `lt_bits`.
+//
+// With a field edaBit (r, [r]_B) the parties open c = x - r, which reveals
+// nothing about x, and add the public c to the bits of r with the shared
+// binary adder: x = c + r < 2p, reduced modulo p on the bits.
+
+use bitvec::prelude::*;
+
+use crate::comparison::get_rand_field_edabit;
+use crate::edabits::{add_bits, reduce_bits};
+use crate::error::Result;
+use crate::fastfield::{FE, N_BITS};
+use crate::gates;
+use crate::party::Party;
+
+/// Decomposes the additively shared `x` into its `N_BITS` bits.
+///
+/// Returns this party's XOR shares of the bits of `x`, `[LSB, ..., MSB]`.
+pub fn a2b(p: &mut Party, x: FE) -> Result<BitVec<u8>> {
+    p.metered("a2b", |p| {
+        const N: usize = N_BITS as usize;
+        let (r, r_bits) = get_rand_field_edabit(p)?;
+        let c = p.open(x - r)?;
+
+        // only P0 inputs the public c
+        let c_bits = if p.id == 0 { gates::to_bits(c.value(), N) } else { bitvec![u8, Lsb0; 0; N] };
+        let s = add_bits(p, &c_bits, &r_bits)?;
+        reduce_bits(p, &s)
+    })
+}
+
+/// Decomposes the additively shared `x`, known to be in `[0, 2^K)`, into its
+/// `K` bits, ready for [`lt_bits`](crate::comparison::lt_bits).
+pub fn a2b_bits<const K: usize>(p: &mut Party, x: FE) -> Result<BitVec<u8>> {
+    assert!(K <= N_BITS as usize, "FE values have {} bits", N_BITS);
+    let mut bits = a2b(p, x)?;
+    bits.truncate(K);
+    Ok(bits)
+}
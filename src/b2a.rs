@@ -0,0 +1,71 @@
+// Bit-to-arithmetic conversion: XOR-shared bits, such as the outputs of
+// `lt_bits` and `lt_const`, into additive FE shares.
+//
+// Both conversions use c = c_0 ^ c_1 = c_0 + c_1 - 2 * c_0 * c_1 in some
+// form: with a daBit b the parties open e = c ^ b and only have to multiply
+// the public e with [b]; without the dealer the product of the two private
+// bits c_0 * c_1 is shared with one correlated OT over FE.
+
+use bitvec::prelude::*;
+
+use crate::comparison::get_rand_dabit;
+use crate::edabits::EdaBitSource;
+use crate::error::{Error, Result};
+use crate::fastfield::FE;
+use crate::ot;
+use crate::party::Party;
+
+/// Converts the XOR-shared bit `c` into an additive `FE` share of `c`.
+pub fn b2a(p: &mut Party, c: u8) -> Result<FE> {
+    Ok(b2a_bits(p, BitVec::<u8>::repeat(c & 1 != 0, 1).as_bitslice())?[0])
+}
+
+/// Converts every XOR-shared bit of `bits` into an additive `FE` share,
+/// with daBits under [`EdaBitSource::Dealer`] and with correlated OTs under
+/// [`EdaBitSource::TwoParty`].
+pub fn b2a_bits(p: &mut Party, bits: &BitSlice<u8>) -> Result<Vec<FE>> {
+    p.metered("b2a", |p| match p.edabit_source {
+        EdaBitSource::Dealer => b2a_dabits(p, bits),
+        EdaBitSource::TwoParty => b2a_ot(p, bits),
+    })
+}
+
+/// Converts XOR-shared bits with one dealer daBit each, opening all the
+/// masked bits in a single round.
+//
+// [c] = e + (1 - 2e) * [b] for the open e = c ^ b; only P0 adds the public e.
+pub fn b2a_dabits(p: &mut Party, bits: &BitSlice<u8>) -> Result<Vec<FE>> {
+    let dabits = bits.iter().map(|_| get_rand_dabit(p)).collect::<Result<Vec<_>>>()?;
+    let masked: BitVec<u8> = bits.iter().by_vals().zip(&dabits).map(|(c, &(_, b))| c ^ (b != 0)).collect();
+    let peer: BitVec<u8> = p.exchange(&masked)?;
+    if peer.len() != masked.len() {
+        return Err(Error::Protocol("wrong number of masked bits"));
+    }
+    Ok((masked ^ peer)
+        .iter()
+        .by_vals()
+        .zip(dabits)
+        .map(|(e, (b, _))| {
+            let s = if e { -b } else { b };
+            if p.id == 0 && e { s + FE::from(1u8) } else { s }
+        })
+        .collect())
+}
+
+/// Converts XOR-shared bits without the dealer, with one correlated OT over
+/// `FE` each, P0 being the sender.
+//
+// P0 offers delta = c_0 and keeps s, P1 chooses with c_1 and gets
+// t = s + c_0 * c_1: the shares c_0 + 2s and c_1 - 2t add up to
+// c_0 + c_1 - 2 * c_0 * c_1 = c.
+pub fn b2a_ot(p: &mut Party, bits: &BitSlice<u8>) -> Result<Vec<FE>> {
+    let own: Vec<FE> = bits.iter().by_vals().map(|c| FE::from(c as u8)).collect();
+    if p.id == 0 {
+        let s = ot::cot_send(p, &own)?;
+        Ok(own.iter().zip(s).map(|(&c, s)| c + s + s).collect())
+    } else {
+        let choices: Vec<bool> = bits.iter().by_vals().collect();
+        let t = ot::cot_recv(p, &choices)?;
+        Ok(own.iter().zip(t).map(|(&c, t)| c - t - t).collect())
+    }
+}
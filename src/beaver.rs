@@ -0,0 +1,142 @@
+// Beaver multiplication triples for additively shared values.
+//
+// The dealer samples (a, b, c = a * b) and shares each component; the
+// parties then multiply two shared values with one round of opening.
+//
+// Without the dealer, triples over FE are generated by the two parties with
+// Gilboa's OT-based product sharing (N. Gilboa, "Two Party RSA Key
+// Generation", CRYPTO 1999): the product of a private x and a private y is
+// shared with one correlated OT per bit of y.
+
+use serde::{Deserialize, Serialize};
+
+use rand::Rng;
+
+use crate::error::Result;
+use crate::fastfield::{Group, Share, FE, N_BITS};
+use crate::ot;
+use crate::party::Party;
+
+/// Where [`mul`] gets its triples from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TripleSource {
+    /// The trusted dealer.
+    Dealer,
+    /// [`gen_triples`], no dealer involved.
+    Gilboa,
+}
+
+/// Multiplication triple `(a, b, c)` with `c = a * b`, one share per party.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Triple<T> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+}
+
+impl<T: Share + Copy> Triple<T> {
+    /// Dealer: samples a random triple from `rng` and shares it additively.
+    pub fn new_shared(rng: &mut impl Rng) -> (Triple<T>, Triple<T>) {
+        let a: T = random_from(rng);
+        let b: T = random_from(rng);
+        let mut c = a;
+        c.mul(&b);
+
+        let (a_0, a_1) = share_from(rng, &a);
+        let (b_0, b_1) = share_from(rng, &b);
+        let (c_0, c_1) = share_from(rng, &c);
+        (Triple { a: a_0, b: b_0, c: c_0 }, Triple { a: a_1, b: b_1, c: c_1 })
+    }
+}
+
+/// Samples a uniformly random element from `rng`.
+pub fn random_from<T: Share>(rng: &mut impl Rng) -> T {
+    let mut out = T::zero();
+    out.from_rng(rng);
+    out
+}
+
+/// Additively shares `value` with randomness from `rng`.
+pub fn share_from<T: Share>(rng: &mut impl Rng, value: &T) -> (T, T) {
+    let s0: T = random_from(rng);
+    let mut s1 = value.clone();
+    s1.sub(&s0);
+    (s0, s1)
+}
+
+// Gilboa, sender of x: N_BITS correlated OTs with delta_j = 2^j * x, chosen
+// by the bits y_j of the receiver's y, give the receiver
+// sum_j (s_j + y_j * 2^j * x) = x * y + sum_j s_j, and the sender keeps
+// -sum_j s_j.
+fn gilboa_send(p: &mut Party, xs: &[FE]) -> Result<Vec<FE>> {
+    let deltas: Vec<FE> = xs
+        .iter()
+        .flat_map(|&x| (0..N_BITS).map(move |j| x * FE::new(1 << j)))
+        .collect();
+    let s = ot::cot_send(p, &deltas)?;
+    Ok(s.chunks(N_BITS as usize).map(|s| -s.iter().fold(FE::zero(), |acc, &s_j| acc + s_j)).collect())
+}
+
+// Gilboa, receiver of y.
+fn gilboa_recv(p: &mut Party, ys: &[FE]) -> Result<Vec<FE>> {
+    let choices: Vec<bool> = ys
+        .iter()
+        .flat_map(|y| {
+            let y = y.value();
+            (0..N_BITS).map(move |j| (y >> j) & 1 == 1)
+        })
+        .collect();
+    let t = ot::cot_recv(p, &choices)?;
+    Ok(t.chunks(N_BITS as usize).map(|t| t.iter().fold(FE::zero(), |acc, &t_j| acc + t_j)).collect())
+}
+
+/// Generates this party's shares of `n` triples over `FE` together with the
+/// other party, without the dealer.
+//
+// c = (a_0 + a_1) * (b_0 + b_1): each party multiplies its own a_i * b_i, and
+// the cross terms a_0 * b_1 and a_1 * b_0 are shared with Gilboa's protocol,
+// every party being the sender of its a_i. The correlated OTs come from the
+// IKNP extension in p.ot_ext.
+pub fn gen_triples(p: &mut Party, n: usize) -> Result<Vec<Triple<FE>>> {
+    p.metered("gen_triples", |p| {
+        let a: Vec<FE> = (0..n).map(|_| FE::random()).collect();
+        let b: Vec<FE> = (0..n).map(|_| FE::random()).collect();
+        // P0 sends first, P1 mirrors it
+        let (u, v) = if p.id == 0 {
+            let u = gilboa_send(p, &a)?;
+            (u, gilboa_recv(p, &b)?)
+        } else {
+            let v = gilboa_recv(p, &b)?;
+            (gilboa_send(p, &a)?, v)
+        };
+        Ok((0..n).map(|i| Triple { a: a[i], b: b[i], c: a[i] * b[i] + u[i] + v[i] }).collect())
+    })
+}
+
+/// Shared multiplication of the additively shared `FE` values `x` and `y`,
+/// consuming one [`Triple`] from `p.triple_source`.
+///
+/// Returns this party's additive share of `x * y`.
+//
+// MUL with a Beaver triple (a, b, c = a * b):
+//  both parties open d = x - a and e = y - b, then
+//  x * y = c + d * b + e * a + d * e, where only P0 adds d * e
+pub fn mul(p: &mut Party, x: FE, y: FE) -> Result<FE> {
+    p.metered("mul", |p| {
+        let t = match p.triple_source {
+            TripleSource::Dealer => p.dealer.field_triple()?,
+            TripleSource::Gilboa => gen_triples(p, 1)?[0],
+        };
+
+        // Online Phase - open d and e
+        let (d_peer, e_peer): (FE, FE) = p.exchange(&(x - t.a, y - t.b))?;
+        let d = x - t.a + d_peer;
+        let e = y - t.b + e_peer;
+
+        let mut z = t.c + d * t.b + e * t.a;
+        if p.id == 0 {
+            z += d * e;
+        }
+        Ok(z)
+    })
+}
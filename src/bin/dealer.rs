@@ -0,0 +1,48 @@
+// Trusted dealer of the 2+1 setting, run as its own process.
+//
+// Listens on `--listen <addr>` and serves sessions one after the other: each
+// session waits for both parties to connect (`--dealer <addr>` on the main
+// binary) and streams them their halves of the correlations they request.
+
+use std::env;
+use std::net::TcpListener;
+use std::process;
+
+use rabbit_mpc_comparisons::dealer::serve_session;
+
+const USAGE: &str = "usage: dealer --listen <addr> [--sessions <n>]";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut listen = None;
+    // Without a limit, sessions are served forever.
+    let mut sessions = None;
+    let mut args = env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+        match flag.as_str() {
+            "--listen" => listen = Some(value),
+            "--sessions" => sessions = Some(value.parse::<usize>().unwrap_or_else(|_| usage())),
+            _ => usage(),
+        }
+    }
+    let listen = listen.unwrap_or_else(|| usage());
+
+    let listener = TcpListener::bind(&listen).unwrap_or_else(|e| {
+        eprintln!("dealer: cannot listen on {}: {}", listen, e);
+        process::exit(1);
+    });
+    println!("dealer: listening on {}", listen);
+    let mut served = 0;
+    while sessions.is_none_or(|n| served < n) {
+        match serve_session(&listener) {
+            Ok(()) => println!("dealer: session {} done", served),
+            Err(e) => eprintln!("dealer: session {} failed: {}", served, e),
+        }
+        served += 1;
+    }
+}
@@ -0,0 +1,204 @@
+// Point-to-point channels between the two parties.
+//
+// A channel moves opaque byte messages; the typed send/recv helpers encode
+// any serde-serialisable message with bincode on top of them.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::dealer::{Dealer, SharedDealer};
+use crate::error::{Error, Result};
+use crate::party::Party;
+
+pub trait Channel: Send {
+    /// Sends one message to the other party.
+    fn send_bytes(&mut self, msg: &[u8]) -> Result<()>;
+
+    /// Receives the next message from the other party.
+    fn recv_bytes(&mut self) -> Result<Vec<u8>>;
+
+    /// Shuts the channel down once both parties are done with it.
+    fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Encodes and sends a message.
+    fn send<T: Serialize>(&mut self, msg: &T) -> Result<()>
+    where
+        Self: Sized,
+    {
+        self.send_bytes(&bincode::serialize(msg)?)
+    }
+
+    /// Receives and decodes a message.
+    fn recv<T: DeserializeOwned>(&mut self) -> Result<T>
+    where
+        Self: Sized,
+    {
+        Ok(bincode::deserialize(&self.recv_bytes()?)?)
+    }
+}
+
+impl<C: Channel + ?Sized> Channel for Box<C> {
+    fn send_bytes(&mut self, msg: &[u8]) -> Result<()> {
+        (**self).send_bytes(msg)
+    }
+
+    fn recv_bytes(&mut self) -> Result<Vec<u8>> {
+        (**self).recv_bytes()
+    }
+
+    fn close(&mut self) -> Result<()> {
+        (**self).close()
+    }
+}
+
+/// In-memory channel between two threads of the same process.
+pub struct MemoryChannel {
+    tx: mpsc::Sender<Vec<u8>>,
+    rx: mpsc::Receiver<Vec<u8>>,
+}
+
+impl MemoryChannel {
+    /// Returns the two connected ends, one per party.
+    pub fn pair() -> (MemoryChannel, MemoryChannel) {
+        let (tx_0, rx_1) = mpsc::channel();
+        let (tx_1, rx_0) = mpsc::channel();
+        (MemoryChannel { tx: tx_0, rx: rx_0 }, MemoryChannel { tx: tx_1, rx: rx_1 })
+    }
+}
+
+impl Channel for MemoryChannel {
+    fn send_bytes(&mut self, msg: &[u8]) -> Result<()> {
+        self.tx.send(msg.to_vec()).map_err(|_| Error::Disconnected)
+    }
+
+    fn recv_bytes(&mut self) -> Result<Vec<u8>> {
+        self.rx.recv().map_err(|_| Error::Disconnected)
+    }
+}
+
+/// Largest message accepted by [`TcpChannel`].
+pub const MAX_FRAME_LEN: usize = 1 << 26;
+
+/// TCP channel between two processes. Every message is framed with its
+/// length as a little-endian `u32`.
+pub struct TcpChannel {
+    stream: TcpStream,
+}
+
+impl TcpChannel {
+    /// Waits on `addr` for the other party to connect.
+    pub fn listen(addr: impl ToSocketAddrs) -> Result<TcpChannel> {
+        TcpChannel::accept(&TcpListener::bind(addr)?)
+    }
+
+    /// Waits for the next connection on `listener`.
+    pub fn accept(listener: &TcpListener) -> Result<TcpChannel> {
+        let (stream, _) = listener.accept()?;
+        TcpChannel::from_stream(stream)
+    }
+
+    /// Connects to the other party listening on `addr`, retrying for up to
+    /// `retries` times half a second apart while it is starting up.
+    pub fn connect(addr: impl ToSocketAddrs, retries: usize) -> Result<TcpChannel> {
+        let mut attempt = 0;
+        loop {
+            match TcpStream::connect(&addr) {
+                Ok(stream) => return TcpChannel::from_stream(stream),
+                Err(e) if attempt < retries && e.kind() == io::ErrorKind::ConnectionRefused => {
+                    attempt += 1;
+                    thread::sleep(Duration::from_millis(500));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    fn from_stream(stream: TcpStream) -> Result<TcpChannel> {
+        // Protocol messages are tiny and latency bound.
+        stream.set_nodelay(true)?;
+        Ok(TcpChannel { stream })
+    }
+}
+
+impl Channel for TcpChannel {
+    fn send_bytes(&mut self, msg: &[u8]) -> Result<()> {
+        if msg.len() > MAX_FRAME_LEN {
+            return Err(Error::Protocol("message too large"));
+        }
+        let mut frame = Vec::with_capacity(4 + msg.len());
+        frame.extend_from_slice(&(msg.len() as u32).to_le_bytes());
+        frame.extend_from_slice(msg);
+        self.stream.write_all(&frame)?;
+        Ok(())
+    }
+
+    fn recv_bytes(&mut self) -> Result<Vec<u8>> {
+        let mut len = [0u8; 4];
+        match self.stream.read_exact(&mut len) {
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Err(Error::Disconnected),
+            other => other?,
+        }
+        let len = u32::from_le_bytes(len) as usize;
+        if len > MAX_FRAME_LEN {
+            return Err(Error::Protocol("message too large"));
+        }
+        let mut msg = vec![0u8; len];
+        self.stream.read_exact(&mut msg)?;
+        Ok(msg)
+    }
+
+    // Half-closes our side, then waits for the other party to do the same,
+    // so that neither side tears the connection down with data in flight.
+    fn close(&mut self) -> Result<()> {
+        self.stream.shutdown(Shutdown::Write)?;
+        let mut rest = Vec::new();
+        self.stream.read_to_end(&mut rest)?;
+        if !rest.is_empty() {
+            return Err(Error::Protocol("unread messages at shutdown"));
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` for both parties concurrently, one thread each, connected by a
+/// [`MemoryChannel`] and served by an in-process trusted dealer.
+///
+/// `f` is called with each party's own [`Party`] and must only use that
+/// party's inputs. Returns `(output_0, output_1)`.
+pub fn run_in_memory<R, F>(f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> R + Sync,
+{
+    let dealer = SharedDealer::new();
+    run_with_dealers([Box::new(dealer.handle(0)), Box::new(dealer.handle(1))], f)
+}
+
+/// Same as [`run_in_memory`], with party `i` served by `dealers[i]`, e.g. a
+/// [`Preprocessing`](crate::preprocessing::Preprocessing) from the offline
+/// phase.
+pub fn run_with_dealers<R, F>(dealers: [Box<dyn Dealer>; 2], f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> R + Sync,
+{
+    let (chan_0, chan_1) = MemoryChannel::pair();
+    let [dealer_0, dealer_1] = dealers;
+    let mut p0 = Party::new(0, Box::new(chan_0), dealer_0);
+    let mut p1 = Party::new(1, Box::new(chan_1), dealer_1);
+
+    thread::scope(|s| {
+        let f = &f;
+        let h1 = s.spawn(move || f(&mut p1));
+        let out_0 = f(&mut p0);
+        let out_1 = h1.join().expect("party 1 panicked");
+        (out_0, out_1)
+    })
+}
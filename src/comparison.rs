@@ -0,0 +1,548 @@
+// https://eprint.iacr.org/2021/119.pdf
+// LTBits and LTConst, and the equality tests built on the same edaBits
+
+use bitvec::prelude::*;
+use fast_math::log2_raw;
+use rand::Rng;
+use debug_print::debug_println;
+
+use crate::b2a::b2a;
+use crate::edabits::{self, EdaBitSource};
+use crate::error::Result;
+use crate::fastfield::{FE, N_BITS, PRIME_ORDER};
+use crate::fastfield::Group;
+use crate::gates;
+use crate::party::Party;
+use crate::z2k::Z2k;
+
+/// Statistical security parameter of [`lt_const`]: the opened `x + r` is
+/// within statistical distance `2^-SIGMA` of a value independent of `x`.
+pub const SIGMA: usize = 30;
+
+/// Largest bit length for which [`lt_const`] opens `x + r` with a `K`-bit
+/// edaBit: the masked `x + r` has `K + SIGMA + 1` bits and must not wrap
+/// around the `FE` modulus. Above it, [`lt_const`] runs over the whole field.
+pub const MASKED_MAX_K: usize = N_BITS as usize - SIGMA - 2;
+
+/// Largest bit length supported by [`lt_const`]: every `K`-bit value must be
+/// a reduced `FE` element, and `2^(N_BITS - 1) < PRIME_ORDER`.
+pub const MAX_K: usize = N_BITS as usize - 1;
+
+// Mask selecting the K least significant bits.
+fn mask<const K: usize>() -> u64 {
+    if K >= 64 { u64::MAX } else { (1 << K) - 1 }
+}
+
+/// Fetches this party's share of a `K`-bit edaBit from `p.edabit_source`: a
+/// random `r` in `[0, 2^K)` shared both additively over `FE` and as `K`
+/// XOR-shared bits `[LSB, ..., MSB]`.
+pub fn get_rand_edabit<const K: usize>(p: &mut Party) -> Result<(FE, BitVec<u8>)> {
+    match p.edabit_source {
+        EdaBitSource::Dealer => {
+            let e = p.dealer.edabit(K)?;
+            Ok((e.r, e.bits))
+        }
+        EdaBitSource::TwoParty => edabits::gen_edabit::<K>(p),
+    }
+}
+
+/// Fetches this party's share of a field edaBit from `p.edabit_source`: a
+/// uniformly random `r` in `[0, PRIME_ORDER)` shared additively over `FE`
+/// and as `N_BITS` XOR-shared bits.
+pub fn get_rand_field_edabit(p: &mut Party) -> Result<(FE, BitVec<u8>)> {
+    match p.edabit_source {
+        EdaBitSource::Dealer => {
+            let e = p.dealer.field_edabit()?;
+            Ok((e.r, e.bits))
+        }
+        EdaBitSource::TwoParty => edabits::gen_field_edabit(p),
+    }
+}
+
+/// Fetches this party's share of a ring edaBit from `p.edabit_source`: a
+/// random `r` in `Z_{2^K}` shared additively over [`Z2k`] and as `K`
+/// XOR-shared bits.
+pub fn get_rand_ring_edabit<const K: usize>(p: &mut Party) -> Result<(Z2k<K>, BitVec<u8>)> {
+    match p.edabit_source {
+        EdaBitSource::Dealer => {
+            let e = p.dealer.ring_edabit(K)?;
+            Ok((Z2k::new(e.r), e.bits))
+        }
+        EdaBitSource::TwoParty => edabits::gen_ring_edabit::<K>(p),
+    }
+}
+
+/// Fetches this party's share of a daBit from the dealer: a random bit `b`
+/// shared both additively over `FE` and as a XOR-shared bit.
+pub fn get_rand_dabit(p: &mut Party) -> Result<(FE, u8)> {
+    let d = p.dealer.dabit()?;
+    Ok((d.r, d.bit))
+}
+
+// Index pairs (i, j) of the PreOpL network on k bits: each step replaces
+// y_j with y_i | y_j, with i > j.
+fn pre_or_pairs(k: usize) -> impl Iterator<Item = (usize, usize)> {
+    let log_m = log2_raw(k as f32).ceil() as usize;
+    (0..log_m).flat_map(move |i| {
+        (0..k.div_ceil(1 << (i + 1))).flat_map(move |j| {
+            let y = ((1 << i) + j * (1 << (i + 1))) - 1;
+            (1..(1 << (i + 1)))
+                .filter(move |z| y + z < k)
+                .map(move |z| (k - 1 - y, k - 1 - y - z))
+        })
+    })
+}
+
+// PreOpL: replaces each shared bit y_i of the K-bit vector with the prefix
+// OR y_{K-1} | ... | y_i, starting from the MSB.
+fn pre_or<const K: usize>(p: &mut Party, y_bits: &mut BitVec<u8>) -> Result<()> {
+    for (i, j) in pre_or_pairs(K) {
+        let or = gates::or_gate(p, y_bits[i], y_bits[j])?;
+        y_bits.set(j, or);
+    }
+    Ok(())
+}
+
+/// Number of shared `OR` gates evaluated by one [`lt_bits`] call on `k`
+/// bits.
+pub fn lt_bits_gates(k: usize) -> usize {
+    pre_or_pairs(k).count()
+}
+
+/// LTBits: compares a public `K`-bit constant against a XOR-shared `K`-bit
+/// vector.
+///
+/// Returns this party's XOR share of `[R <= x]`, where `sh` is this party's
+/// share of `x`.
+pub fn lt_bits<const K: usize>(p: &mut Party, const_r: u64, sh: &BitVec<u8>) -> Result<u8> {
+    p.metered("lt_bits", |p| {
+        assert!(const_r <= mask::<K>(), "R must fit in {} bits", K);
+        assert_eq!(sh.len(), K, "x must have {} bits", K);
+        let r_bits = const_r.view_bits::<Lsb0>().to_bitvec();
+
+        // Step 1 - only P0 adds the public bits
+        let mut y_bits = bitvec![u8, Lsb0; 0; K];
+        for i in 0..K {
+            y_bits.set(i, if p.id == 0 { sh[i] ^ r_bits[i] } else { sh[i] });
+        }
+
+        // Step 2 - PreOpL
+        pre_or::<K>(p, &mut y_bits)?;
+        y_bits.push(false);
+        let z_bits = y_bits;
+
+        // Step 3
+        let mut w_bits = bitvec![u8, Lsb0; 0; K];
+        for i in 0..K {
+            w_bits.set(i, z_bits[i] ^ z_bits[i+1]); // -
+        }
+
+        // Step 4 - only the parity of the sum is needed
+        let mut sum = false;
+        for i in 0..K {
+            sum ^= r_bits[i] & w_bits[i];
+        }
+
+        Ok(if p.id == 0 { 1 - sum as u8 } else { sum as u8 })
+    })
+}
+
+/// Strict LTBits: returns this party's XOR share of `[R < x]` for a public
+/// `K`-bit constant `R` and a XOR-shared `K`-bit vector `x`.
+pub fn lt_bits_strict<const K: usize>(p: &mut Party, const_r: u64, sh: &BitVec<u8>) -> Result<u8> {
+    // [R < x] = [R + 1 <= x], and nothing is larger than 2^K - 1.
+    if const_r >= mask::<K>() {
+        return Ok(0);
+    }
+    lt_bits::<K>(p, const_r + 1, sh)
+}
+
+/// Secret LTBits: compares two XOR-shared `K`-bit vectors `r` and `x`.
+///
+/// Same structure as [`lt_bits`], except that `r` is shared as well: the
+/// prefix OR locates the most significant bit where `r` and `x` differ, and
+/// shared AND gates select `r`'s bit at that position.
+///
+/// Returns this party's XOR share of `[r <= x]`.
+pub fn lt_bits_secret<const K: usize>(p: &mut Party, r: &BitVec<u8>, sh: &BitVec<u8>) -> Result<u8> {
+    p.metered("lt_bits_secret", |p| {
+        assert_eq!(r.len(), K, "r must have {} bits", K);
+        assert_eq!(sh.len(), K, "x must have {} bits", K);
+        // Step 1
+        let mut y_bits = bitvec![u8, Lsb0; 0; K];
+        for i in 0..K {
+            y_bits.set(i, sh[i] ^ r[i]);
+        }
+
+        // Step 2 - PreOpL
+        pre_or::<K>(p, &mut y_bits)?;
+        y_bits.push(false);
+        let z_bits = y_bits;
+
+        // Step 3
+        let mut w_bits = bitvec![u8, Lsb0; 0; K];
+        for i in 0..K {
+            w_bits.set(i, z_bits[i] ^ z_bits[i+1]); // -
+        }
+
+        // Step 4 - r_i * w_i needs a shared AND gate
+        let mut sum = false;
+        for i in 0..K {
+            sum ^= gates::and_gate(p, r[i], w_bits[i])?;
+        }
+
+        Ok(if p.id == 0 { 1 - sum as u8 } else { sum as u8 })
+    })
+}
+
+// Opens a = x + r mod M = 2^K for an FE value x in [0, 2^K) and this party's
+// share of a K-bit edaBit r.
+//
+// Opened as an integer, x + r with a K-bit r leaks x (a = 0 means x = 0), so
+// every party also adds 2^K * h_i for a private h_i in [0, 2^SIGMA): to each
+// party, r + 2^K * h of the other party is uniform on K + SIGMA bits and
+// hides x within 2^-SIGMA. The h_i vanish modulo M, and the sum stays below
+// 2^(K + SIGMA + 1) < p.
+fn open_masked<const K: usize>(p: &mut Party, x: FE, r: FE) -> Result<u64> {
+    let h = rand::thread_rng().gen_range(0..(1u64 << SIGMA));
+    let a = p.open(x + r + FE::new(h << K))?;
+    Ok(a.value() % (1 << K))
+}
+
+// Steps 3 and 4 of LTConst on the opened a = x + r mod M, with this party's
+// bits of r: its XOR share of [x < T] = 1 - (w1 - w2 + w3), where
+// b = a + M - T mod M, w1 = [a < r], w2 = [b < r] and w3 = [b < M - T].
+// M and T are u128 so that the ring's 2^64 fits.
+fn lt_opened<const K: usize>(p: &mut Party, const_m: u128, a: u64, r_bits: &BitVec<u8>, t: u128) -> Result<u8> {
+    let w1 = lt_bits_strict::<K>(p, a, r_bits)?;
+    debug_println!("\tw1 share (LTbits(a < r)): {}", w1);
+    let w2_w3 = bound_term::<K>(p, const_m, a, r_bits, t)?;
+
+    // Step 4 - w = 1 - (w1 - w2 + w3), the public 1 added by P0
+    let w = gates::not_gate(p, (w1 ^ w2_w3) != 0) as u8;
+    debug_println!("\tw share (1 - (w1 - w2 + w3)): {}", w);
+    Ok(w)
+}
+
+// The terms of LTConst's [x < T] that depend on the bound T: this party's XOR
+// share of w2 - w3, the public w3 added by P0.
+fn bound_term<const K: usize>(p: &mut Party, const_m: u128, a: u64, r_bits: &BitVec<u8>, t: u128) -> Result<u8> {
+    let b = ((a as u128 + const_m - t) % const_m) as u64;
+    let w2 = lt_bits_strict::<K>(p, b, r_bits)?;
+    // M - T is 0 when T = M, so w3 is then 0
+    let w3 = ((b as u128) < const_m - t) as u8;
+    debug_println!("\tb (= a + M - T mod M): {}", b);
+    debug_println!("\tw2 share (LTbits(b < r)): {}", w2);
+    debug_println!("\tw3 ((b < M - T) -- {} < {}): {}", b, const_m - t, w3);
+    Ok(if p.id == 0 { w2 ^ w3 } else { w2 })
+}
+
+/// LTConst: compares an additively shared `FE` value `x` in `[0, 2^K)`
+/// against a public constant `R` in `[0, 2^K)`, with `K` at most [`MAX_K`].
+///
+/// Up to [`MASKED_MAX_K`] bits it works modulo `M = 2^K` with a `K`-bit
+/// edaBit, and the opening of `x + r` is statistically hiding with [`SIGMA`]
+/// bits of slack. Wider inputs take the perfectly hiding [`lt_const_field`]
+/// path, which gives the same result since `x` and `R` are below the modulus.
+///
+/// Returns this party's XOR share of `[x <= R]`.
+pub fn lt_const<const K: usize>(p: &mut Party, const_r: u64, x: FE) -> Result<u8> {
+    p.metered("lt_const", |p| {
+        assert!(K <= MAX_K, "LTConst supports at most {} bits", MAX_K);
+        assert!(const_r <= mask::<K>(), "R must fit in {} bits", K);
+        if K > MASKED_MAX_K {
+            return field_lt_const(p, const_r, x);
+        }
+        let (r, r_bits) = get_rand_edabit::<K>(p)?;
+
+        // Steps 1 and 2 - open a = x + r mod M
+        let a = open_masked::<K>(p, x, r)?;
+        debug_println!("P{} LTConst, R = {}, M = 2^{}, a = {}", p.id, const_r, K, a);
+
+        // [x <= R] = [x < R + 1]
+        lt_opened::<K>(p, 1 << K, a, &r_bits, const_r as u128 + 1)
+    })
+}
+
+/// LTConst over the prime field: compares an additively shared `FE` value
+/// `x` against a public constant `R`, both in `[0, PRIME_ORDER)`, working
+/// modulo `M = PRIME_ORDER` as in the prime-field variant of Rabbit.
+///
+/// Returns this party's XOR share of `[x <= R]`.
+pub fn lt_const_field(p: &mut Party, const_r: u64, x: FE) -> Result<u8> {
+    p.metered("lt_const_field", |p| {
+        assert!(const_r < PRIME_ORDER, "R must be a reduced field element");
+        field_lt_const(p, const_r, x)
+    })
+}
+
+// LTConst modulo M = PRIME_ORDER with a field edaBit, unmetered.
+fn field_lt_const(p: &mut Party, const_r: u64, x: FE) -> Result<u8> {
+    const K: usize = N_BITS as usize;
+    let (r, r_bits) = get_rand_field_edabit(p)?;
+
+    // Steps 1 and 2 - open a = x + r; the field reduces it mod M
+    let a = p.open(x + r)?.value();
+    debug_println!("P{} LTConst (field), R = {}, M = {}, a = {}", p.id, const_r, PRIME_ORDER, a);
+
+    lt_opened::<K>(p, PRIME_ORDER as u128, a, &r_bits, const_r as u128 + 1)
+}
+
+/// LTConst over the ring `Z_{2^K}`: compares an additively shared [`Z2k`]
+/// value `x` against a public constant `R` in `[0, 2^K)`. All reductions
+/// modulo `M = 2^K` are wrapping integer arithmetic, with no field involved.
+///
+/// Returns this party's XOR share of `[x <= R]`.
+pub fn lt_const_ring<const K: usize>(p: &mut Party, const_r: u64, x: Z2k<K>) -> Result<u8> {
+    p.metered("lt_const_ring", |p| {
+        assert!(const_r <= mask::<K>(), "R must fit in {} bits", K);
+        let (r, r_bits) = get_rand_ring_edabit::<K>(p)?;
+
+        // Steps 1 and 2 - open a = x + r; the ring reduces it mod M
+        let mut a = x;
+        a.add(&r);
+        let a = p.open(a)?.value();
+        debug_println!("P{} LTConst (ring), R = {}, M = 2^{}, a = {}", p.id, const_r, K, a);
+
+        lt_opened::<K>(p, 1 << K, a, &r_bits, const_r as u128 + 1)
+    })
+}
+
+/// Largest value accepted by [`lt_secret`]: both inputs must lie in
+/// `[0, (PRIME_ORDER - 1) / 2]` so that the sign of `x - y` is well defined.
+pub const SECRET_CMP_MAX: u64 = (PRIME_ORDER - 1) / 2;
+
+/// Secret comparison: compares two additively shared `FE` values `x` and
+/// `y`, both in `[0, SECRET_CMP_MAX]`.
+///
+/// As in Rabbit, `x < y` exactly when `d = x - y mod PRIME_ORDER` wraps into
+/// the upper half of the field, so `[x < y] = 1 - [d <= (PRIME_ORDER - 1) / 2]`
+/// is a single [`lt_const_field`] on the locally computed `d`.
+///
+/// Returns this party's XOR share of `[x < y]`.
+pub fn lt_secret(p: &mut Party, x: FE, y: FE) -> Result<u8> {
+    p.metered("lt_secret", |p| {
+        // Step 1 - d = x - y, computed locally
+        let mut d = x;
+        d.sub(&y);
+
+        // Step 2 - [d <= (p - 1) / 2] = [x >= y]
+        let w = lt_const_field(p, SECRET_CMP_MAX, d)?;
+
+        debug_println!("Secret comparison:");
+        debug_println!("\td (= x - y mod p) <= (p - 1) / 2 share: {}", w);
+
+        // Step 3 - negate
+        Ok(if p.id == 0 { 1 - w } else { w })
+    })
+}
+
+/// Secret comparison with field output: same as [`lt_secret`], but the
+/// result `[x < y]` is converted to an additive `FE` share with [`b2a`].
+pub fn lt_secret_fe(p: &mut Party, x: FE, y: FE) -> Result<FE> {
+    let c = lt_secret(p, x, y)?;
+    b2a(p, c)
+}
+
+// Zero test: this party's XOR share of [y == 0] for the XOR-shared bits y,
+// i.e. the negated OR of all of them.
+fn is_zero(p: &mut Party, y_bits: &BitSlice<u8>) -> Result<u8> {
+    let mut any = y_bits[0];
+    for y_i in y_bits[1..].iter().by_vals() {
+        any = gates::or_gate(p, any, y_i)?;
+    }
+    Ok(gates::not_gate(p, any) as u8)
+}
+
+/// EQBits: compares a public `K`-bit constant against a XOR-shared `K`-bit
+/// vector.
+///
+/// Returns this party's XOR share of `[x == R]`, where `sh` is this party's
+/// share of `x`.
+pub fn eq_bits<const K: usize>(p: &mut Party, const_r: u64, sh: &BitVec<u8>) -> Result<u8> {
+    p.metered("eq_bits", |p| {
+        assert!(const_r <= mask::<K>(), "R must fit in {} bits", K);
+        assert_eq!(sh.len(), K, "x must have {} bits", K);
+        // only P0 adds the public bits: y = x ^ R is zero iff x == R
+        let r_bits = gates::to_bits(const_r, K);
+        let y_bits: BitVec<u8> = (0..K).map(|i| if p.id == 0 { sh[i] ^ r_bits[i] } else { sh[i] }).collect();
+        is_zero(p, &y_bits)
+    })
+}
+
+/// EQConst: compares an additively shared `FE` value `x` against a public
+/// constant `R`, both in `[0, PRIME_ORDER)`.
+///
+/// Returns this party's XOR share of `[x == R]`.
+//
+// With a field edaBit r, open c = x - R + r: x == R iff c == r, which is a
+// bitwise zero test on c ^ r, c being public.
+pub fn eq_const(p: &mut Party, const_r: u64, x: FE) -> Result<u8> {
+    p.metered("eq_const", |p| {
+        const K: usize = N_BITS as usize;
+        assert!(const_r < PRIME_ORDER, "R must be a reduced field element");
+        let (r, r_bits) = get_rand_field_edabit(p)?;
+
+        // Step 1 - only P0 subtracts the public R
+        let mut a = x + r;
+        if p.id == 0 {
+            a.sub(&FE::new(const_r));
+        }
+
+        // Step 2 - open c, masked by r
+        let c = p.open(a)?.value();
+
+        // Step 3 - zero test on c ^ r
+        eq_bits::<K>(p, c, &r_bits)
+    })
+}
+
+/// Secret equality: compares two additively shared `FE` values `x` and `y`.
+///
+/// Returns this party's XOR share of `[x == y]`.
+pub fn eq_secret(p: &mut Party, x: FE, y: FE) -> Result<u8> {
+    p.metered("eq_secret", |p| eq_const(p, 0, x - y))
+}
+
+// Interval test on the opened a = x + r mod M: the w1 = [a < r] terms of
+// LTConst's [x < U] and [x < L] are the same, so they cancel out in
+// [x < U] - [x < L] along with the 1s, and only the bound terms remain.
+fn interval_opened<const K: usize>(p: &mut Party, const_m: u128, a: u64, r_bits: &BitVec<u8>, lo: u64, hi: u64) -> Result<u8> {
+    Ok(bound_term::<K>(p, const_m, a, r_bits, lo as u128)? ^ bound_term::<K>(p, const_m, a, r_bits, hi as u128)?)
+}
+
+/// Interval test: checks whether an additively shared `FE` value `x` in
+/// `[0, 2^K)` lies in the public range `[L, U)`, with `L <= U <= 2^K`.
+///
+/// Both bounds share one edaBit and one opening, which saves an edaBit, an
+/// opening and two LTBits over two [`lt_const`] calls. As in [`lt_const`],
+/// the opening is masked statistically up to [`MASKED_MAX_K`] bits, and wider
+/// inputs take the [`interval_field`] path.
+///
+/// Returns this party's XOR share of `[L <= x < U]`.
+pub fn interval<const K: usize>(p: &mut Party, lo: u64, hi: u64, x: FE) -> Result<u8> {
+    p.metered("interval", |p| {
+        assert!(K <= MAX_K, "LTConst supports at most {} bits", MAX_K);
+        assert!(lo <= hi && hi <= 1 << K, "the range must be within [0, 2^{}]", K);
+        if K > MASKED_MAX_K {
+            return field_interval(p, lo, hi, x);
+        }
+        let (r, r_bits) = get_rand_edabit::<K>(p)?;
+        let a = open_masked::<K>(p, x, r)?;
+        interval_opened::<K>(p, 1 << K, a, &r_bits, lo, hi)
+    })
+}
+
+/// Interval test over the prime field: checks whether an additively shared
+/// `FE` value `x` lies in the public range `[L, U)`, with
+/// `L <= U <= PRIME_ORDER`. Same saving as [`interval`].
+///
+/// Returns this party's XOR share of `[L <= x < U]`.
+pub fn interval_field(p: &mut Party, lo: u64, hi: u64, x: FE) -> Result<u8> {
+    p.metered("interval_field", |p| {
+        assert!(lo <= hi && hi <= PRIME_ORDER, "the range must be within [0, PRIME_ORDER]");
+        field_interval(p, lo, hi, x)
+    })
+}
+
+// The interval test modulo M = PRIME_ORDER with a field edaBit, unmetered.
+fn field_interval(p: &mut Party, lo: u64, hi: u64, x: FE) -> Result<u8> {
+    const K: usize = N_BITS as usize;
+    let (r, r_bits) = get_rand_field_edabit(p)?;
+
+    // the field reduces a = x + r mod M
+    let a = p.open(x + r)?.value();
+    interval_opened::<K>(p, PRIME_ORDER as u128, a, &r_bits, lo, hi)
+}
+
+/// Comparison operator of [`compare_const`] and its variants, which all
+/// compute `[x op R]` for a shared `x` and a public `R`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    /// `x < R`
+    Lt,
+    /// `x <= R`
+    Le,
+    /// `x > R`
+    Gt,
+    /// `x >= R`
+    Ge,
+}
+
+impl CmpOp {
+    /// All four operators.
+    pub const ALL: [CmpOp; 4] = [CmpOp::Lt, CmpOp::Le, CmpOp::Gt, CmpOp::Ge];
+
+    /// Evaluates `x op R` in the clear.
+    pub fn eval<T: PartialOrd>(self, x: T, const_r: T) -> bool {
+        match self {
+            CmpOp::Lt => x < const_r,
+            CmpOp::Le => x <= const_r,
+            CmpOp::Gt => x > const_r,
+            CmpOp::Ge => x >= const_r,
+        }
+    }
+}
+
+// [x op R] for x in [0, max], from le(p, T) = [x <= T] for T in [0, max):
+// [x < R] = [x <= R - 1], [x > R] = 1 - [x <= R] and [x >= R] = 1 - [x <= R - 1].
+// The bounds that fall outside the domain, R - 1 = -1 or R >= max, give a
+// public result, input by P0.
+fn compare_with(
+    p: &mut Party,
+    op: CmpOp,
+    const_r: u64,
+    max: u64,
+    le: impl FnOnce(&mut Party, u64) -> Result<u8>,
+) -> Result<u8> {
+    let (t, negate) = match op {
+        CmpOp::Le => (Some(const_r), false),
+        CmpOp::Lt => (const_r.checked_sub(1), false),
+        CmpOp::Gt => (Some(const_r), true),
+        CmpOp::Ge => (const_r.checked_sub(1), true),
+    };
+    let public = |p: &Party, b: bool| (p.id == 0 && b) as u8;
+    let w = match t {
+        None => public(p, false),
+        Some(t) if t >= max => public(p, true),
+        Some(t) => le(p, t)?,
+    };
+    Ok(if negate { gates::not_gate(p, w != 0) as u8 } else { w })
+}
+
+/// Compares a XOR-shared `K`-bit vector `x` against any public constant `R`
+/// with [`lt_bits`].
+///
+/// Returns this party's XOR share of `[x op R]`, where `sh` is this party's
+/// share of `x`.
+pub fn compare_bits<const K: usize>(p: &mut Party, op: CmpOp, const_r: u64, sh: &BitVec<u8>) -> Result<u8> {
+    // [x <= T] = 1 - [T < x]
+    compare_with(p, op, const_r, mask::<K>(), |p, t| {
+        let w = lt_bits_strict::<K>(p, t, sh)?;
+        Ok(gates::not_gate(p, w != 0) as u8)
+    })
+}
+
+/// Compares an additively shared `FE` value `x` in `[0, 2^K)` against any
+/// public constant `R` with [`lt_const`].
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const<const K: usize>(p: &mut Party, op: CmpOp, const_r: u64, x: FE) -> Result<u8> {
+    compare_with(p, op, const_r, mask::<K>(), |p, t| lt_const::<K>(p, t, x))
+}
+
+/// Compares an additively shared `FE` value `x` against any public constant
+/// `R` with [`lt_const_field`].
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const_field(p: &mut Party, op: CmpOp, const_r: u64, x: FE) -> Result<u8> {
+    compare_with(p, op, const_r, PRIME_ORDER - 1, |p, t| lt_const_field(p, t, x))
+}
+
+/// Compares an additively shared [`Z2k`] value `x` against any public
+/// constant `R` with [`lt_const_ring`].
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const_ring<const K: usize>(p: &mut Party, op: CmpOp, const_r: u64, x: Z2k<K>) -> Result<u8> {
+    compare_with(p, op, const_r, mask::<K>(), |p, t| lt_const_ring::<K>(p, t, x))
+}
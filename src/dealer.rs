@@ -0,0 +1,422 @@
+// Correlated randomness handed out by a trusted dealer.
+//
+// The dealer generates each correlation in full and gives every party only
+// its own half. Protocols ask for the next correlation of a given `Kind`;
+// both parties ask for the same kinds in the same order, so their halves
+// match up.
+
+use bitvec::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::beaver::{random_from, share_from, Triple};
+use crate::channel::{Channel, TcpChannel};
+use crate::error::{Error, Result};
+use crate::fastfield::{FE, N_BITS};
+use crate::gates::{self, BoolTriple};
+
+/// Kind of correlation requested from the dealer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Kind {
+    /// Random OT correlation in which party `sender` is the OT sender.
+    Ot { sender: usize },
+    /// Boolean Beaver triple.
+    BoolTriple,
+    /// Beaver triple over `FE`.
+    FieldTriple,
+    /// edaBit with `r` in `[0, 2^bits)`, additively shared over `FE`.
+    EdaBit { bits: usize },
+    /// edaBit with `r` uniform over `FE`.
+    FieldEdaBit,
+    /// edaBit with `r` in `Z_{2^bits}`, additively shared over the ring.
+    RingEdaBit { bits: usize },
+    /// daBit, a random bit shared both over `FE` and as a XOR share.
+    DaBit,
+}
+
+impl Kind {
+    // Whether `deal` can generate this kind, for kinds read off the wire.
+    fn is_valid(self) -> bool {
+        match self {
+            Kind::Ot { sender } => sender < 2,
+            Kind::EdaBit { bits } | Kind::RingEdaBit { bits } => (1..=64).contains(&bits),
+            _ => true,
+        }
+    }
+
+    // Distinct tag of every kind, used to derive per-kind randomness.
+    fn tag(self) -> u64 {
+        match self {
+            Kind::Ot { sender } => sender as u64,
+            Kind::BoolTriple => 2,
+            Kind::FieldTriple => 3,
+            Kind::EdaBit { bits } => (1 << 8) | bits as u64,
+            Kind::FieldEdaBit => 4,
+            Kind::RingEdaBit { bits } => (2 << 8) | bits as u64,
+            Kind::DaBit => 5,
+        }
+    }
+}
+
+/// OT sender's half: the two random keys `k`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OtSender {
+    pub k: [u8; 2],
+}
+
+/// OT receiver's half: the random choice `c` and the key `kc = k[c]`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OtReceiver {
+    pub c: u8,
+    pub kc: u8,
+}
+
+/// One party's share of an edaBit: `r` shared additively (over `T`) and as
+/// XOR-shared bits `[LSB, ..., MSB]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdaBit<T> {
+    pub r: T,
+    pub bits: BitVec<u8>,
+}
+
+/// One party's share of a daBit: `b` shared additively over `FE` and as a
+/// XOR-shared bit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DaBit {
+    pub r: FE,
+    pub bit: u8,
+}
+
+/// One party's half of a correlation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Correlation {
+    OtSender(OtSender),
+    OtReceiver(OtReceiver),
+    BoolTriple(BoolTriple),
+    FieldTriple(Triple<FE>),
+    EdaBit(EdaBit<FE>),
+    // The ring share is kept as its raw value modulo 2^bits.
+    RingEdaBit(EdaBit<u64>),
+    DaBit(DaBit),
+}
+
+/// Generates a correlation of the given kind from `rng`, returning the half
+/// of each party indexed by party id.
+pub fn deal(kind: Kind, rng: &mut impl Rng) -> [Correlation; 2] {
+    match kind {
+        Kind::Ot { sender } => {
+            let k = [rng.gen::<u8>() % 2, rng.gen::<u8>() % 2];
+            let c = rng.gen::<u8>() % 2;
+            let s = Correlation::OtSender(OtSender { k });
+            let r = Correlation::OtReceiver(OtReceiver { c, kc: k[c as usize] });
+            if sender == 0 { [s, r] } else { [r, s] }
+        }
+        Kind::BoolTriple => {
+            let (t_0, t_1) = BoolTriple::new_shared(rng);
+            [Correlation::BoolTriple(t_0), Correlation::BoolTriple(t_1)]
+        }
+        Kind::FieldTriple => {
+            let (t_0, t_1) = Triple::<FE>::new_shared(rng);
+            [Correlation::FieldTriple(t_0), Correlation::FieldTriple(t_1)]
+        }
+        Kind::EdaBit { bits } => {
+            let r = rng.gen::<u64>() & mask(bits);
+            let (r_0, r_1) = share_from(rng, &FE::new(r));
+            let [b_0, b_1] = share_bits(rng, r, bits);
+            [
+                Correlation::EdaBit(EdaBit { r: r_0, bits: b_0 }),
+                Correlation::EdaBit(EdaBit { r: r_1, bits: b_1 }),
+            ]
+        }
+        Kind::FieldEdaBit => {
+            let r: FE = random_from(rng);
+            let (r_0, r_1) = share_from(rng, &r);
+            let [b_0, b_1] = share_bits(rng, r.value(), N_BITS as usize);
+            [
+                Correlation::EdaBit(EdaBit { r: r_0, bits: b_0 }),
+                Correlation::EdaBit(EdaBit { r: r_1, bits: b_1 }),
+            ]
+        }
+        Kind::RingEdaBit { bits } => {
+            let r = rng.gen::<u64>() & mask(bits);
+            let r_0 = rng.gen::<u64>() & mask(bits);
+            let r_1 = r.wrapping_sub(r_0) & mask(bits);
+            let [b_0, b_1] = share_bits(rng, r, bits);
+            [
+                Correlation::RingEdaBit(EdaBit { r: r_0, bits: b_0 }),
+                Correlation::RingEdaBit(EdaBit { r: r_1, bits: b_1 }),
+            ]
+        }
+        Kind::DaBit => {
+            let b = rng.gen::<bool>();
+            let bit_0 = rng.gen::<bool>() as u8;
+            let bit_1 = bit_0 ^ b as u8;
+            let (r_0, r_1) = share_from(rng, &FE::new(b as u64));
+            [
+                Correlation::DaBit(DaBit { r: r_0, bit: bit_0 }),
+                Correlation::DaBit(DaBit { r: r_1, bit: bit_1 }),
+            ]
+        }
+    }
+}
+
+// Mask selecting the `bits` least significant bits.
+fn mask(bits: usize) -> u64 {
+    if bits >= 64 { u64::MAX } else { (1 << bits) - 1 }
+}
+
+// XOR-shares the `bits` least significant bits of r.
+fn share_bits(rng: &mut impl Rng, r: u64, bits: usize) -> [BitVec<u8>; 2] {
+    let r_bits = gates::to_bits(r, bits);
+    let b_0: BitVec<u8> = (0..bits).map(|_| rng.gen::<bool>()).collect();
+    let b_1 = b_0.clone() ^ r_bits;
+    [b_0, b_1]
+}
+
+/// Source of one party's correlated randomness.
+pub trait Dealer: Send {
+    /// Returns this party's half of the next correlation of the given kind.
+    fn next(&mut self, kind: Kind) -> Result<Correlation>;
+
+    /// OT sender's half of the next OT correlation sent by `sender`.
+    fn ot_sender(&mut self, sender: usize) -> Result<OtSender> {
+        match self.next(Kind::Ot { sender })? {
+            Correlation::OtSender(c) => Ok(c),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    /// OT receiver's half of the next OT correlation sent by `sender`.
+    fn ot_receiver(&mut self, sender: usize) -> Result<OtReceiver> {
+        match self.next(Kind::Ot { sender })? {
+            Correlation::OtReceiver(c) => Ok(c),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn bool_triple(&mut self) -> Result<BoolTriple> {
+        match self.next(Kind::BoolTriple)? {
+            Correlation::BoolTriple(t) => Ok(t),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn field_triple(&mut self) -> Result<Triple<FE>> {
+        match self.next(Kind::FieldTriple)? {
+            Correlation::FieldTriple(t) => Ok(t),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn edabit(&mut self, bits: usize) -> Result<EdaBit<FE>> {
+        match self.next(Kind::EdaBit { bits })? {
+            Correlation::EdaBit(e) => Ok(e),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn field_edabit(&mut self) -> Result<EdaBit<FE>> {
+        match self.next(Kind::FieldEdaBit)? {
+            Correlation::EdaBit(e) => Ok(e),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn ring_edabit(&mut self, bits: usize) -> Result<EdaBit<u64>> {
+        match self.next(Kind::RingEdaBit { bits })? {
+            Correlation::RingEdaBit(e) => Ok(e),
+            _ => Err(wrong_kind()),
+        }
+    }
+
+    fn dabit(&mut self) -> Result<DaBit> {
+        match self.next(Kind::DaBit)? {
+            Correlation::DaBit(d) => Ok(d),
+            _ => Err(wrong_kind()),
+        }
+    }
+}
+
+fn wrong_kind() -> Error {
+    Error::Protocol("the dealer returned the wrong kind of correlation")
+}
+
+type Pending = HashMap<Kind, [VecDeque<Correlation>; 2]>;
+
+/// Trusted dealer shared by two parties running in the same process.
+///
+/// Correlations are generated on the first request of either party; the
+/// other party's half is queued until it asks for the same kind.
+#[derive(Clone, Default)]
+pub struct SharedDealer {
+    pending: Arc<Mutex<Pending>>,
+}
+
+impl SharedDealer {
+    pub fn new() -> SharedDealer {
+        SharedDealer::default()
+    }
+
+    /// Returns the dealer endpoint of party `id`.
+    pub fn handle(&self, id: usize) -> DealerHandle {
+        DealerHandle { id, pending: Arc::clone(&self.pending) }
+    }
+}
+
+/// One party's endpoint of a [`SharedDealer`].
+pub struct DealerHandle {
+    id: usize,
+    pending: Arc<Mutex<Pending>>,
+}
+
+impl Dealer for DealerHandle {
+    fn next(&mut self, kind: Kind) -> Result<Correlation> {
+        let mut pending = self.pending.lock().expect("dealer lock poisoned");
+        let queues = pending.entry(kind).or_default();
+        if let Some(c) = queues[self.id].pop_front() {
+            return Ok(c);
+        }
+        let [c_0, c_1] = deal(kind, &mut rand::thread_rng());
+        let (mine, other) = if self.id == 0 { (c_0, c_1) } else { (c_1, c_0) };
+        queues[1 - self.id].push_back(other);
+        Ok(mine)
+    }
+}
+
+/// Dealer of a party that must not use one, e.g. when every correlation
+/// comes from the parties themselves: every request fails.
+pub struct NoDealer;
+
+impl Dealer for NoDealer {
+    fn next(&mut self, _kind: Kind) -> Result<Correlation> {
+        Err(Error::Protocol("no dealer in this setting"))
+    }
+}
+
+/// Dealer simulated by both parties from a common seed, for running the two
+/// parties in separate processes without a dealer process.
+///
+/// Each party regenerates every correlation in full and keeps its own half,
+/// so it also learns the other party's half: this offers no security and is
+/// only meant for testing and benchmarking.
+pub struct SeededDealer {
+    id: usize,
+    seed: u64,
+    // One stream per kind, so the order of requests across kinds does not
+    // need to match between the parties.
+    streams: HashMap<Kind, StdRng>,
+}
+
+impl SeededDealer {
+    pub fn new(id: usize, seed: u64) -> SeededDealer {
+        SeededDealer { id, seed, streams: HashMap::new() }
+    }
+}
+
+impl Dealer for SeededDealer {
+    fn next(&mut self, kind: Kind) -> Result<Correlation> {
+        let seed = self.seed;
+        let rng = self.streams.entry(kind).or_insert_with(|| {
+            StdRng::seed_from_u64(seed ^ kind.tag().wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        });
+        let [c_0, c_1] = deal(kind, rng);
+        Ok(if self.id == 0 { c_0 } else { c_1 })
+    }
+}
+
+/// Request of a party to a dealer server: its next `count` correlations of
+/// the given kind.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Request {
+    pub kind: Kind,
+    pub count: usize,
+}
+
+/// Correlations fetched at once by a [`RemoteDealer`].
+pub const REMOTE_BATCH: usize = 1024;
+// Largest request served by `serve_session`.
+const MAX_REQUEST: usize = 1 << 16;
+
+/// One party's connection to a dealer server run by a third party (see
+/// [`serve_session`]), fetching correlations in batches of
+/// [`REMOTE_BATCH`].
+pub struct RemoteDealer {
+    chan: TcpChannel,
+    queues: HashMap<Kind, VecDeque<Correlation>>,
+}
+
+impl RemoteDealer {
+    /// Connects party `id` to the dealer server listening on `addr`.
+    pub fn connect(addr: impl ToSocketAddrs, id: usize) -> Result<RemoteDealer> {
+        let mut chan = TcpChannel::connect(addr, 20)?;
+        chan.send(&id)?;
+        Ok(RemoteDealer { chan, queues: HashMap::new() })
+    }
+}
+
+impl Dealer for RemoteDealer {
+    fn next(&mut self, kind: Kind) -> Result<Correlation> {
+        let queue = self.queues.entry(kind).or_default();
+        if queue.is_empty() {
+            self.chan.send(&Request { kind, count: REMOTE_BATCH })?;
+            let batch: Vec<Correlation> = self.chan.recv()?;
+            if batch.len() != REMOTE_BATCH {
+                return Err(Error::Protocol("the dealer returned a short batch"));
+            }
+            queue.extend(batch);
+        }
+        Ok(queue.pop_front().expect("batch is not empty"))
+    }
+}
+
+/// Dealer server: accepts the connections of both parties on `listener`
+/// and answers their [`Request`]s from a [`SharedDealer`] until both hang
+/// up.
+///
+/// The server only ever sees which kinds of correlations are requested,
+/// never the parties' inputs.
+pub fn serve_session(listener: &TcpListener) -> Result<()> {
+    let mut chans: [Option<TcpChannel>; 2] = [None, None];
+    while chans.iter().any(Option::is_none) {
+        let mut chan = TcpChannel::accept(listener)?;
+        let id: usize = chan.recv()?;
+        match chans.get_mut(id) {
+            Some(slot @ None) => *slot = Some(chan),
+            _ => return Err(Error::Protocol("invalid or duplicate party id")),
+        }
+    }
+    let dealer = SharedDealer::new();
+    let [chan_0, chan_1] = chans.map(|c| c.expect("both parties connected"));
+
+    thread::scope(|s| {
+        let h1 = s.spawn(|| serve_party(chan_1, dealer.handle(1)));
+        let out_0 = serve_party(chan_0, dealer.handle(0));
+        let out_1 = h1.join().expect("dealer thread panicked");
+        out_0.and(out_1)
+    })
+}
+
+// Answers the requests of one party until it disconnects.
+fn serve_party(mut chan: TcpChannel, mut dealer: DealerHandle) -> Result<()> {
+    loop {
+        let req: Request = match chan.recv() {
+            Err(Error::Disconnected) => return Ok(()),
+            other => other?,
+        };
+        if req.count > MAX_REQUEST {
+            return Err(Error::Protocol("request too large"));
+        }
+        if !req.kind.is_valid() {
+            return Err(Error::Protocol("invalid correlation kind"));
+        }
+        let batch = (0..req.count)
+            .map(|_| dealer.next(req.kind))
+            .collect::<Result<Vec<_>>>()?;
+        chan.send(&batch)?;
+    }
+}
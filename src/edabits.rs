@@ -0,0 +1,120 @@
+// Dealer-free edaBit generation, after Escudero et al., "Improved Primitives
+// for MPC over Mixed Arithmetic-Binary Circuits" (eprint 2020/338).
+//
+// Each party samples its own random r_i and inputs it both additively (its
+// share is r_i, the other party's is 0) and as bits (likewise). The bits of
+// r = r_0 + r_1 are computed with a shared binary adder; the arithmetic side
+// is corrected for the carry out of the adder where the sum is not reduced
+// for free.
+
+use bitvec::prelude::*;
+use rand::Rng;
+
+use crate::b2a;
+use crate::error::Result;
+use crate::fastfield::{Share, FE, N_BITS, PRIME_ORDER};
+use crate::gates;
+use crate::party::Party;
+use crate::z2k::Z2k;
+
+/// Where the protocols get their edaBits from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdaBitSource {
+    /// The trusted dealer, which learns `r`.
+    Dealer,
+    /// The two-party generation protocol in this module, no dealer involved.
+    TwoParty,
+}
+
+// This party's XOR share of the len-bit private input v of party `owner`.
+fn input_bits(p: &Party, owner: usize, v: u64, len: usize) -> BitVec<u8> {
+    if p.id == owner { gates::to_bits(v, len) } else { bitvec![u8, Lsb0; 0; len] }
+}
+
+/// Shared ripple-carry adder on two XOR-shared bit vectors of the same
+/// length, `[LSB, ..., MSB]`.
+///
+/// Returns this party's share of the bits of `x + y`, one longer than the
+/// inputs: the last bit is the carry out.
+//
+// s_i = x_i + y_i + c, and the carry maj(x_i, y_i, c) = ((x_i + c) & (y_i + c)) + c
+// takes a single AND gate per bit.
+pub fn add_bits(p: &mut Party, x: &BitVec<u8>, y: &BitVec<u8>) -> Result<BitVec<u8>> {
+    assert_eq!(x.len(), y.len());
+    let mut sum = BitVec::<u8>::with_capacity(x.len() + 1);
+    let mut c = false;
+    for (x_i, y_i) in x.iter().by_vals().zip(y.iter().by_vals()) {
+        sum.push(x_i ^ y_i ^ c);
+        c = gates::and_gate(p, x_i ^ c, y_i ^ c)? ^ c;
+    }
+    sum.push(c);
+    Ok(sum)
+}
+
+/// Generates this party's share of a `K`-bit edaBit over `Z_{2^K}` without
+/// the dealer: the adder's carry out is simply dropped, as is the ring's.
+pub fn gen_ring_edabit<const K: usize>(p: &mut Party) -> Result<(Z2k<K>, BitVec<u8>)> {
+    p.metered("gen_ring_edabit", |p| {
+        let r_i = Z2k::<K>::random();
+        let r_0 = input_bits(p, 0, r_i.value(), K);
+        let r_1 = input_bits(p, 1, r_i.value(), K);
+        let mut bits = add_bits(p, &r_0, &r_1)?;
+        bits.truncate(K);
+        Ok((r_i, bits))
+    })
+}
+
+/// Generates this party's share of a `K`-bit edaBit with `r` in `[0, 2^K)`
+/// shared over `FE` without the dealer.
+//
+// r = r_0 + r_1 - 2^K * c, where c is the adder's carry out, so the XOR
+// shared c is converted to an FE share first.
+pub fn gen_edabit<const K: usize>(p: &mut Party) -> Result<(FE, BitVec<u8>)> {
+    p.metered("gen_edabit", |p| {
+        assert!(K < N_BITS as usize, "edaBits over FE must have less than {} bits", N_BITS);
+        let r_i = rand::thread_rng().gen_range(0..(1u64 << K));
+        let r_0 = input_bits(p, 0, r_i, K);
+        let r_1 = input_bits(p, 1, r_i, K);
+        let mut bits = add_bits(p, &r_0, &r_1)?;
+        let c = bits.pop().expect("adder output has a carry");
+
+        let c = b2a::b2a_ot(p, BitVec::<u8>::repeat(c, 1).as_bitslice())?[0];
+        Ok((FE::new(r_i) - c * FE::new(1 << K), bits))
+    })
+}
+
+/// Generates this party's share of a field edaBit, `r` uniform over `FE`,
+/// without the dealer.
+//
+// FE addition reduces r_0 + r_1 mod p for free, so only the bits need the
+// reduction: s = r_0 + r_1 has N_BITS + 1 bits and is less than 2p.
+pub fn gen_field_edabit(p: &mut Party) -> Result<(FE, BitVec<u8>)> {
+    p.metered("gen_field_edabit", |p| {
+        const N: usize = N_BITS as usize;
+        let r_i = FE::random();
+        let r_0 = input_bits(p, 0, r_i.value(), N);
+        let r_1 = input_bits(p, 1, r_i.value(), N);
+        let s = add_bits(p, &r_0, &r_1)?;
+        Ok((r_i, reduce_bits(p, &s)?))
+    })
+}
+
+/// Reduces the XOR-shared `N_BITS + 1`-bit value `s < 2p` modulo the field
+/// prime. Returns this party's share of the `N_BITS` bits of `s mod p`.
+//
+// s - p is computed as s + (2^(N_BITS + 1) - p), whose carry out is [s >= p]
+// and selects between the two.
+pub fn reduce_bits(p: &mut Party, s: &BitVec<u8>) -> Result<BitVec<u8>> {
+    const N: usize = N_BITS as usize;
+    assert_eq!(s.len(), N + 1);
+    // only P0 holds the public constant
+    let neg_p = input_bits(p, 0, (1 << (N + 1)) - PRIME_ORDER, N + 1);
+    let d = add_bits(p, s, &neg_p)?;
+    let ge = d[N + 1];
+
+    let mut bits = BitVec::<u8>::with_capacity(N);
+    for i in 0..N {
+        bits.push(gates::mux_gate(p, ge, s[i], d[i])?);
+    }
+    Ok(bits)
+}
@@ -0,0 +1,48 @@
+// Errors returned by the two-party protocols.
+
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+use crate::dealer::Kind;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying transport failed.
+    Io(io::Error),
+    /// A message could not be encoded or decoded.
+    Codec(bincode::Error),
+    /// The other party hung up.
+    Disconnected,
+    /// The other party or the dealer deviated from the protocol.
+    Protocol(&'static str),
+    /// The preprocessed correlations of this kind ran out.
+    Exhausted(Kind),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Codec(e) => write!(f, "codec error: {}", e),
+            Error::Disconnected => write!(f, "the other party disconnected"),
+            Error::Protocol(msg) => write!(f, "protocol error: {}", msg),
+            Error::Exhausted(kind) => write!(f, "preprocessed {:?} correlations exhausted", kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<bincode::Error> for Error {
+    fn from(e: bincode::Error) -> Self {
+        Error::Codec(e)
+    }
+}
@@ -19,11 +19,11 @@ use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 // Here are the constants that determine our prime:
 //
 // number of bits in our field elements
-const N_BITS: u64 = 42;
+pub const N_BITS: u64 = 42;
 // Which bit (other than bit 0) do we clear in our prime?
 const OFFSET_BIT: u64 = 30;
 // order of the prime field
-const PRIME_ORDER: u64 = (1 << N_BITS) - (1 << OFFSET_BIT) - 1;
+pub const PRIME_ORDER: u64 = (1 << N_BITS) - (1 << OFFSET_BIT) - 1;
 
 // There are some constraints on those constants, as described here:
 //
@@ -136,7 +136,8 @@ impl Group for FE {
 }
 
 pub trait FromRng {
-    fn from_rng(&mut self, stream: &mut (impl rand::Rng + rand_core::RngCore));
+    #[allow(clippy::wrong_self_convention)]
+    fn from_rng(&mut self, stream: &mut impl rand::Rng);
 
     fn randomize(&mut self) {
         self.from_rng(&mut rand::thread_rng());
@@ -242,7 +243,7 @@ impl FE {
     }
     fn new_raw(v: u32) -> Self {
         // Since v <= u32::MAX, we know that it is less than FE_VAL_MAX.
-        debug_assert!((std::u32::MAX as u64) < FE_VAL_MAX);
+        debug_assert!((u32::MAX as u64) < FE_VAL_MAX);
         FE { val: v as u64 }
     }
     // Return the value of this FE, as an integer in range 0..PRIME_ORDER-1.
@@ -301,7 +302,7 @@ impl From<u16> for FE {
 }
 impl From<u32> for FE {
     fn from(v: u32) -> FE {
-        FE::new_raw(v as u32)
+        FE::new_raw(v)
     }
 }
 
@@ -456,40 +457,40 @@ impl RemAssign for FE {
     }
 }
 
-impl<'a> Add<&'a FE> for FE {
+impl Add<&FE> for FE {
     type Output = Self;
     fn add(self, rhs: &Self) -> FE {
         self + *rhs
     }
 }
-impl<'a> Sub<&'a FE> for FE {
+impl Sub<&FE> for FE {
     type Output = Self;
     fn sub(self, rhs: &Self) -> FE {
         self - *rhs
     }
 }
 
-impl<'a, 'b> Sub<&'b FE> for &'a FE {
+impl Sub<&FE> for &FE {
     type Output = FE;
 
-    fn sub(self, rhs: &'b FE) -> FE {
+    fn sub(self, rhs: &FE) -> FE {
         *self - *rhs
     }
 }
 
-impl<'a> Mul<&'a FE> for FE {
+impl Mul<&FE> for FE {
     type Output = Self;
     fn mul(self, rhs: &Self) -> FE {
         self * *rhs
     }
 }
-impl<'a> Div<&'a FE> for FE {
+impl Div<&FE> for FE {
     type Output = Self;
     fn div(self, rhs: &Self) -> FE {
         self / *rhs
     }
 }
-impl<'a> Rem<&'a FE> for FE {
+impl Rem<&FE> for FE {
     type Output = Self;
     fn rem(self, rhs: &Self) -> FE {
         self % *rhs
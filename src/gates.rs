@@ -1,96 +1,218 @@
 use bitvec::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-pub const ITER: usize = 100;
-pub const M: usize = 8;  // number of bits
+use crate::error::Result;
+use crate::ot;
+use crate::party::Party;
 
-pub struct Dealer {
-    k: Vec<u8>,
-    c: u8,
-    kc: u8,
+/// Boolean Beaver triple `(a, b, c)` with `c = a & b`, one share per party.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BoolTriple {
+    pub a: bool,
+    pub b: bool,
+    pub c: bool,
 }
 
-impl Dealer {
-    pub fn new() -> Dealer {
-        let mut rng = rand::thread_rng();
-        let k = vec![rng.gen::<u8>() % 2, rng.gen::<u8>() % 2];
-        let c = rng.gen::<u8>() % 2;
-        Dealer { kc: k[c as usize], k: k, c: c, }
+impl BoolTriple {
+    /// Dealer: samples a random triple from `rng` and XOR-shares it.
+    pub fn new_shared(rng: &mut impl Rng) -> (BoolTriple, BoolTriple) {
+        let (a, b) = (rng.gen::<bool>(), rng.gen::<bool>());
+        let t0 = BoolTriple { a: rng.gen(), b: rng.gen(), c: rng.gen() };
+        let t1 = BoolTriple { a: a ^ t0.a, b: b ^ t0.b, c: (a & b) ^ t0.c };
+        (t0, t1)
     }
 }
 
+/// Implementation of the interactive gates (`AND`, `OR` and `MUX`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateKind {
+    /// Two dealer OT correlations per gate.
+    Ot,
+    /// One dealer Beaver triple and one opening per gate.
+    Triple,
+}
+
+/// Returns the `len` least significant bits of `v` as `[LSB, ..., MSB]`.
+pub fn to_bits(v: u64, len: usize) -> BitVec<u8> {
+    v.to_le_bytes().view_bits::<Lsb0>()[..len].to_bitvec()
+}
+
+/// XOR-shares `bit_array` between two parties.
 pub fn secret_share(bit_array: &BitVec<u8>) -> (BitVec<u8>, BitVec<u8>) {
     let mut rng = rand::thread_rng();
-    let mut sh_1 = BitVec::<u8>::with_capacity(M);
-    let mut sh_2 = BitVec::<u8>::with_capacity(M);
-    for i in 0..M {
+    let mut sh_1 = BitVec::<u8>::with_capacity(bit_array.len());
+    let mut sh_2 = BitVec::<u8>::with_capacity(bit_array.len());
+    for i in 0..bit_array.len() {
         sh_1.push(rng.gen::<bool>());
         sh_2.push(sh_1[i] ^ bit_array[i]);
     }
     (sh_1, sh_2)
 }
 
-pub fn _reconstruct_shares(ss0: &BitVec<u8>, ss1: &BitVec<u8>) -> BitVec<u8> {
+/// Reconstructs a bit vector from its two XOR shares.
+pub fn reconstruct_shares(ss0: &BitVec<u8>, ss1: &BitVec<u8>) -> BitVec<u8> {
     assert_eq!(ss0.len(), ss1.len());
-    let mut reconstructed = BitVec::<u8>::with_capacity(M);
+    let mut reconstructed = BitVec::<u8>::with_capacity(ss0.len());
     for (b0, b1) in ss0.iter().zip(ss1.iter()) {
         reconstructed.push(*b0 ^ *b1);
     }
     reconstructed
 }
 
-// P0 is the Sender with inputs (m0, m1)
-// P1 is the Receiver with inputs (b, mb)
-pub fn one_out_of_two_ot(
-    dealer: &Dealer,
-    receiver_b: u8,
-    sender_m: &Vec<u8>) -> u8
-{
-    let z = receiver_b ^ dealer.c;
-    let y = {
-        if z == 0 {
-            vec![sender_m[0] ^ dealer.k[0], sender_m[1] ^ dealer.k[1]]
-        } else {
-            vec![sender_m[0] ^ dealer.k[1], sender_m[1] ^ dealer.k[0]]
+/// One party's input to [`one_out_of_two_ot`].
+#[derive(Debug, Clone, Copy)]
+pub enum OtInput {
+    /// The sender's messages `(m0, m1)`.
+    Sender([u8; 2]),
+    /// The receiver's choice bit `b`.
+    Receiver(u8),
+}
+
+/// 1-out-of-2 OT from a random OT correlation (Beaver's precomputed OT),
+/// taken from `p.ot_source`.
+///
+/// The Sender inputs (m0, m1) and gets 0; the Receiver inputs b and gets mb.
+pub fn one_out_of_two_ot(p: &mut Party, input: OtInput) -> Result<u8> {
+    p.metered("one_out_of_two_ot", |p| {
+        match input {
+            OtInput::Sender(sender_m) => {
+                let dealer = ot::sender_correlation(p)?;
+                // Receiver -> Sender: z = b ^ c
+                let z: u8 = p.recv()?;
+                let y = {
+                    if z == 0 {
+                        [sender_m[0] ^ dealer.k[0], sender_m[1] ^ dealer.k[1]]
+                    } else {
+                        [sender_m[0] ^ dealer.k[1], sender_m[1] ^ dealer.k[0]]
+                    }
+                };
+                // Sender -> Receiver: y
+                p.send(&y)?;
+                Ok(0)
+            }
+            OtInput::Receiver(receiver_b) => {
+                let dealer = ot::receiver_correlation(p)?;
+                let z = receiver_b ^ dealer.c;
+                p.send(&z)?;
+                let y: [u8; 2] = p.recv()?;
+                Ok(y[receiver_b as usize] ^ dealer.kc)
+            }
         }
-    };
+    })
+}
 
-    y[receiver_b as usize] ^ dealer.kc
+/// Shared `OR` of the XOR-shared bit `x` and `y`, using the implementation
+/// selected by `p.gate_kind`.
+///
+/// Returns this party's XOR share of `x | y`.
+pub fn or_gate(p: &mut Party, x: bool, y: bool) -> Result<bool> {
+    p.metered("or_gate", |p| match p.gate_kind {
+        GateKind::Ot => or_gate_ot(p, x, y),
+        GateKind::Triple => or_gate_triple(p, x, y),
+    })
 }
 
+/// OT-based shared `OR`, consuming two dealer OT correlations.
+//
 // OR: z = x | y = ~(~x & ~y)
 //   ~(~x & ~y) = ~(~x * ~y) = ~( ~(p0.x + p1.x) * ~(p0.y + p1.y) ) =
 //  ~( (~p0.x + p1.x) * (~p0.y + p1.y) ) =
 //  ~( (~p0.x * ~p0.y) + (~p0.x * p1.y) + (p1.x * ~p0.y) + (p1.x * p1.y) ) =
-//  P0 computes locally ~p0.x * ~p0.y
+//  i.e. an AND gate on the inputs and the output negated by P0.
+pub fn or_gate_ot(p: &mut Party, x: bool, y: bool) -> Result<bool> {
+    let not_x = not_gate(p, x);
+    let not_y = not_gate(p, y);
+    let and = and_gate_ot(p, not_x, not_y)?;
+    Ok(not_gate(p, and))
+}
+
+/// Shared `AND` of the XOR-shared bits `x` and `y`, using the implementation
+/// selected by `p.gate_kind`.
+///
+/// Returns this party's XOR share of `x & y`.
+pub fn and_gate(p: &mut Party, x: bool, y: bool) -> Result<bool> {
+    p.metered("and_gate", |p| match p.gate_kind {
+        GateKind::Ot => and_gate_ot(p, x, y),
+        GateKind::Triple => and_gate_triple(p, x, y),
+    })
+}
+
+/// OT-based shared `AND`, consuming two dealer OT correlations.
+//
+// AND: z = x & y = (p0.x + p1.x) * (p0.y + p1.y) =
+//  (p0.x * p0.y) + (p0.x * p1.y) + (p1.x * p0.y) + (p1.x * p1.y)
+//  P0 computes locally p0.x * p0.y
 //  P1 computes locally p1.x * p1.y
-//  Both parties compute via OT: ~p0.x * p1.y and p1.x * ~p0.y
-pub fn or_gate(x0: bool, y0: bool, x1: bool, y1: bool) -> (bool, bool) {
+//  Both parties compute via OT: p0.x * p1.y and p1.x * p0.y
+pub fn and_gate_ot(p: &mut Party, x: bool, y: bool) -> Result<bool> {
     let mut rng = rand::thread_rng();
+    let r = rng.gen::<bool>();
+    let sender_m = OtInput::Sender([r as u8, (x as u8) ^ (r as u8)]);
+    let receiver_b = OtInput::Receiver(y as u8);
+
+    // Online Phase - P1 receives r0 + p0.x * p1.y, then
+    //                P0 receives r1 + p1.x * p0.y
+    let r_xy = if p.id == 0 {
+        one_out_of_two_ot(p, sender_m)?;
+        one_out_of_two_ot(p, receiver_b)?
+    } else {
+        let r_xy = one_out_of_two_ot(p, receiver_b)?;
+        one_out_of_two_ot(p, sender_m)?;
+        r_xy
+    } != 0;
+
+    Ok((x & y) ^ (r ^ r_xy))
+}
+
+/// Triple-based shared `AND`, consuming one dealer [`BoolTriple`] and one
+/// round of opening.
+//
+// AND with a Beaver triple (a, b, c = a & b):
+//  both parties open d = x + a and e = y + b, then
+//  x & y = c + d * b + e * a + d * e, where only P0 adds d * e
+pub fn and_gate_triple(p: &mut Party, x: bool, y: bool) -> Result<bool> {
+    let t = p.dealer.bool_triple()?;
+
+    // Online Phase - open d and e
+    let (d_peer, e_peer): (bool, bool) = p.exchange(&(x ^ t.a, y ^ t.b))?;
+    let d = x ^ t.a ^ d_peer;
+    let e = y ^ t.b ^ e_peer;
+
+    let share = t.c ^ (d & t.b) ^ (e & t.a);
+    Ok(if p.id == 0 { share ^ (d & e) } else { share })
+}
+
+/// Triple-based shared `OR`: `x | y = ~(~x & ~y)` on top of
+/// [`and_gate_triple`].
+pub fn or_gate_triple(p: &mut Party, x: bool, y: bool) -> Result<bool> {
+    let not_x = not_gate(p, x);
+    let not_y = not_gate(p, y);
+    let and = and_gate_triple(p, not_x, not_y)?;
+    Ok(not_gate(p, and))
+}
+
+/// Shared `XOR` of the XOR-shared bits `x` and `y`. Local, no interaction.
+///
+/// Returns this party's XOR share of `x ^ y`.
+pub fn xor_gate(x: bool, y: bool) -> bool {
+    x ^ y
+}
+
+/// Shared `NOT` of the XOR-shared bit `x`. Local, only P0 flips its share.
+///
+/// Returns this party's XOR share of `~x`.
+pub fn not_gate(p: &Party, x: bool) -> bool {
+    if p.id == 0 { !x } else { x }
+}
 
-    // Online Phase - P1 receives r0 + p0.x * p1.y
-    let r0 = rng.gen::<bool>();
-    let dealer = Dealer::new();
-    let r0_x0y1 = one_out_of_two_ot(
-        &dealer,
-        y1 as u8,
-        &vec![r0 as u8, (!x0 as u8) ^ (r0 as u8)]
-    ) != 0;
-
-    // Online Phase - P0 receives r1 + p1.x * p0.y
-    let r1 = rng.gen::<bool>();
-    let dealer = Dealer::new();
-    let r1_x1y0 = one_out_of_two_ot(
-        &dealer,
-        !y0 as u8,
-        &vec![r1 as u8, (x1 as u8) ^ (r1 as u8)]
-    ) != 0;
-
-    // P0
-    let share_0 = !( (!x0 & !y0) ^ (r0 ^ r1_x1y0) );
-
-    // P1
-    let share_1 = (x1 & y1) ^ (r1 ^ r0_x0y1);
-
-    (share_0, share_1)
+/// Shared multiplexer on the XOR-shared bits `s`, `x` and `y`.
+///
+/// Returns this party's XOR share of `s ? y : x`.
+//
+// MUX: z = x + s * (x + y), one AND gate on top of local XORs
+pub fn mux_gate(p: &mut Party, s: bool, x: bool, y: bool) -> Result<bool> {
+    let d = xor_gate(x, y);
+    let sd = and_gate(p, s, d)?;
+    Ok(xor_gate(x, sd))
 }
@@ -0,0 +1,77 @@
+//! PoC implementation of the Rabbit comparison protocols from
+//! <https://eprint.iacr.org/2021/119.pdf>
+//!
+//! The crate exposes:
+//!
+//! - [`comparison`]: the `LTBits` and `LTConst` protocols (over `Z_{2^K}`
+//!   embedded in `FE`, and over the prime field itself), the secret-vs-secret
+//!   comparison built on them, the typed `<`, `<=`, `>`, `>=` comparisons
+//!   against constants, the interval and equality tests, and the edaBit
+//!   generation they rely on.
+//! - [`signed`]: signed encodings (centred representatives over [`FE`], two's
+//!   complement on bits and over [`Z2k`]), the signed comparisons and the
+//!   sign bit.
+//! - [`a2b`](mod@a2b): bit decomposition of additive [`FE`] shares into
+//!   XOR-shared bits.
+//! - [`b2a`](mod@b2a): conversion of XOR-shared bits, such as the comparison
+//!   results, into additive [`FE`] shares.
+//! - [`edabits`]: the two-party edaBit generation, an alternative to the
+//!   dealer's edaBits.
+//! - [`gates`]: XOR secret sharing and the shared boolean gates (`AND`, `OR`,
+//!   `XOR`, `NOT`, `MUX`).
+//! - [`ot`]: Chou–Orlandi base OT, IKNP OT extension and correlated OTs over
+//!   [`FE`], alternatives to the dealer's OT correlations.
+//! - [`fastfield`]: the prime field [`FE`] together with the [`Group`] and
+//!   [`Share`] traits used for additive sharing.
+//! - [`z2k`]: the power-of-two ring [`Z2k`], implementing the same traits.
+//! - [`beaver`]: Beaver triples, from the dealer or from Gilboa's OT-based
+//!   product sharing, and the shared multiplication [`mul`].
+//!
+//! - [`party`], [`channel`] and [`dealer`]: the two-party execution model.
+//! - [`preprocessing`]: the offline phase, generating a batch of correlations
+//!   up front and storing each party's half in a file.
+//! - [`stats`]: the communication accounting of every party.
+//!
+//! Every protocol is written from the point of view of one party: it takes
+//! that party's [`Party`] handle and its own shares, exchanges messages with
+//! the other party over a [`Channel`], and returns its own output share.
+//! [`channel::run_in_memory`] runs both parties concurrently in one process.
+
+pub mod a2b;
+pub mod b2a;
+pub mod beaver;
+pub mod channel;
+pub mod comparison;
+pub mod dealer;
+pub mod edabits;
+pub mod error;
+pub mod fastfield;
+pub mod gates;
+pub mod ot;
+pub mod party;
+pub mod preprocessing;
+pub mod signed;
+pub mod stats;
+pub mod z2k;
+
+pub use a2b::{a2b, a2b_bits};
+pub use b2a::{b2a, b2a_bits};
+pub use beaver::{gen_triples, mul, Triple, TripleSource};
+pub use channel::{run_in_memory, run_with_dealers, Channel, MemoryChannel, TcpChannel};
+pub use comparison::{get_rand_dabit, get_rand_edabit, get_rand_field_edabit, get_rand_ring_edabit};
+pub use comparison::{compare_bits, compare_const, compare_const_field, compare_const_ring, CmpOp};
+pub use comparison::{eq_bits, eq_const, eq_secret, interval, interval_field};
+pub use comparison::{lt_bits, lt_bits_secret, lt_bits_strict};
+pub use comparison::{lt_const, lt_const_field, lt_const_ring};
+pub use comparison::{lt_secret, lt_secret_fe};
+pub use dealer::{Dealer, RemoteDealer};
+pub use edabits::EdaBitSource;
+pub use error::{Error, Result};
+pub use fastfield::{Group, Share, FE};
+pub use ot::{OtPool, OtSource};
+pub use party::Party;
+pub use preprocessing::{Batch, Preprocessing};
+pub use signed::{compare_bits_signed, compare_const_field_signed, compare_const_ring_signed, compare_const_signed};
+pub use signed::{lt_secret_signed, sign_bits, sign_field};
+pub use stats::{Cost, Stats};
+pub use z2k::Z2k;
@@ -1,170 +1,421 @@
 // https://eprint.iacr.org/2021/119.pdf
-// LTBits
+// Example driver for the LTBits and LTConst protocols.
+//
+// By default both parties run in this process, one thread each; every
+// protocol call below only hands a party its own shares. With
+// `--party <0|1> --peer <addr>` this process runs a single party and talks
+// to the other one over TCP: party 0 listens on `addr`, party 1 connects.
 
-mod gates;
-mod fastfield;
-
-use bitvec::prelude::*;
 use rand::Rng;
-use fast_math::log2_raw;
+use std::env;
+use std::net::TcpListener;
+use std::process;
+use std::time::{Duration, Instant};
 use debug_print::debug_println;
 
-use crate::fastfield::FE;
-use crate::fastfield::Share;
-use crate::fastfield::Group;
+use bitvec::field::BitField;
+use bitvec::vec::BitVec;
 
-fn get_rand_edabit() -> ((FE, BitVec<u8>), (FE, BitVec<u8>)) {
-    let mut rng = rand::thread_rng();
-    let r = rng.gen::<u8>() % 64;
-    let r_bits = r.view_bits::<Lsb0>().to_bitvec();
-    let (r_0_bits, r_1_bits) = gates::secret_share(&r_bits);
-    let (r_0, r_1) = FE::new(r as u64).share();
-    ((r_0, r_0_bits), (r_1, r_1_bits))
+use rabbit_mpc_comparisons::edabits::EdaBitSource;
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+use rabbit_mpc_comparisons::ot::OtSource;
+use rabbit_mpc_comparisons::fastfield::{N_BITS, PRIME_ORDER};
+use rabbit_mpc_comparisons::comparison::SECRET_CMP_MAX;
+use rabbit_mpc_comparisons::dealer::{NoDealer, SeededDealer};
+use rabbit_mpc_comparisons::{Batch, Dealer, Error, Preprocessing, RemoteDealer};
+use rabbit_mpc_comparisons::{lt_bits, lt_bits_secret, lt_const_field};
+use rabbit_mpc_comparisons::{compare_bits, compare_const, compare_const_field, compare_const_ring, CmpOp};
+use rabbit_mpc_comparisons::signed::{self, FE_SIGNED_MAX};
+use rabbit_mpc_comparisons::sign_field;
+use rabbit_mpc_comparisons::{eq_const, lt_secret, lt_secret_fe};
+use rabbit_mpc_comparisons::a2b_bits;
+use rabbit_mpc_comparisons::{mul, run_in_memory, Group, OtPool, Party, Result, Share, Stats, TcpChannel, FE, Z2k};
+
+// Iterations of the example loop
+const ITER: usize = 100;
+// Bit length of the compared values
+const K: usize = 8;
+// Bit length of the ring Z_{2^RING_K}, matching the field's edaBits
+const RING_K: usize = N_BITS as usize;
+// OTs of each direction extended at once by the OT pool, and held at most
+const POOL_BATCH: usize = 1 << 12;
+const POOL_CAPACITY: usize = 1 << 15;
+
+// Runs `f` for both parties with the given gate implementation, adding each
+// party's communication to `stats`.
+fn run_metered<R, F>(kind: GateKind, stats: &mut [Stats; 2], f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> Result<R> + Sync,
+{
+    let ((out_0, stats_0), (out_1, stats_1)) = run_in_memory(|p| {
+        p.gate_kind = kind;
+        let out = f(p);
+        (out, p.stats.clone())
+    });
+    stats[0].merge(&stats_0);
+    stats[1].merge(&stats_1);
+    (out_0.expect("party 0 failed"), out_1.expect("party 1 failed"))
+}
+
+// Reports the measured communication of one LTBits call.
+fn compare_gates(kind: GateKind) {
+    let x_bits = gates::to_bits(rand::thread_rng().gen_range(0..(1 << K)), K);
+    let (x0, x1) = gates::secret_share(&x_bits);
+    let x_sh = [x0, x1];
+    let mut stats: [Stats; 2] = Default::default();
+    run_metered(kind, &mut stats, |p| lt_bits::<K>(p, 1, &x_sh[p.id]));
+    println!(
+        "LT Bits ({:?} gates): {} gates; P0: {}; P1: {}",
+        kind, stats[0].op("or_gate").calls, stats[0].total, stats[1].total
+    );
+}
+
+// What this process runs.
+enum Mode {
+    // Both parties, in this process.
+    InMemory,
+    // The offline phase: writes the preprocessing of `n` comparisons to
+    // `<prep>.p0` and `<prep>.p1`.
+    Offline { n: usize, prep: String },
+    // A single party of a two-process run.
+    Party(Args),
+}
+
+// Command line of a single party of a two-process run.
+struct Args {
+    party: usize,
+    peer: String,
+    // Seed of the simulated dealer, for testing only.
+    seed: Option<u64>,
+    // Preprocessing file prefix.
+    prep: Option<String>,
+    // Address of a dealer server (`src/bin/dealer.rs`).
+    dealer: Option<String>,
+    edabit_source: EdaBitSource,
+    ot_source: OtSource,
 }
 
-// Returns [c] = [R <= x]
-fn lt_bits(
-    const_r: u8, sh_0: &BitVec<u8>, sh_1: &BitVec<u8>
-) -> (u8, u8) {
-    let r_bits = const_r.view_bits::<Lsb0>().to_bitvec();
-
-    // Step 1
-    let mut y_bits_0 = bitvec![u8, Lsb0; 0; gates::M];
-    let mut y_bits_1 = bitvec![u8, Lsb0; 0; gates::M];
-    for i in 0..gates::M {
-        y_bits_0.set(i, sh_0[i] ^ r_bits[i]);
-        y_bits_1.set(i, sh_1[i]);
+const USAGE: &str = "usage: rabbit-mpc-comparisons \
+    [--party <0|1> --peer <addr> (--seed <n> | --prep <file> | --dealer <addr>) \
+    [--edabits <dealer|two-party>] [--ot <dealer|base|iknp|pool>] \
+    | --offline <n> --prep <file>]";
+
+fn parse_args() -> Mode {
+    let mut party = None;
+    let mut peer = None;
+    let mut seed = None;
+    let mut prep = None;
+    let mut offline = None;
+    let mut dealer = None;
+    let mut edabit_source = EdaBitSource::Dealer;
+    let mut ot_source = OtSource::Dealer;
+    let mut args = env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+        match flag.as_str() {
+            "--party" => party = Some(value.parse().ok().filter(|&id| id < 2).unwrap_or_else(|| usage())),
+            "--peer" => peer = Some(value),
+            "--seed" => seed = Some(value.parse().unwrap_or_else(|_| usage())),
+            "--prep" => prep = Some(value),
+            "--offline" => offline = Some(value.parse().unwrap_or_else(|_| usage())),
+            "--dealer" => dealer = Some(value),
+            "--edabits" => edabit_source = match value.as_str() {
+                "dealer" => EdaBitSource::Dealer,
+                "two-party" => EdaBitSource::TwoParty,
+                _ => usage(),
+            },
+            "--ot" => ot_source = match value.as_str() {
+                "dealer" => OtSource::Dealer,
+                "base" => OtSource::BaseOt,
+                "iknp" => OtSource::Iknp,
+                "pool" => OtSource::Pool,
+                _ => usage(),
+            },
+            _ => usage(),
+        }
+    }
+    // a party needs exactly one source of correlated randomness
+    let sources = seed.is_some() as usize + prep.is_some() as usize + dealer.is_some() as usize;
+    match (party, peer, offline) {
+        (Some(_), Some(_), None) if sources != 1 => usage(),
+        (Some(party), Some(peer), None) => Mode::Party(Args { party, peer, seed, prep, dealer, edabit_source, ot_source }),
+        (None, None, Some(n)) if dealer.is_none() && seed.is_none() => Mode::Offline { n, prep: prep.unwrap_or_else(|| usage()) },
+        (None, None, None) if sources == 0 => Mode::InMemory,
+        _ => usage(),
     }
+}
 
-    // Step 2 - PreOpL
-    let log_m = log2_raw(gates::M as f32).ceil() as usize;
-    for i in 0..log_m {
-        for j in 0..(gates::M / (1 << (i + 1))) {
-            let y = ((1 << i) + j * (1 << (i + 1))) - 1;
-            for z in 1..(1 << (i + 1)) {
-                if y + z < gates::M {
-                    let idx_y = gates::M - 1 - y;
-                    let (or_0, or_1) = gates::or_gate(
-                        y_bits_0[idx_y], y_bits_0[idx_y - z],
-                        y_bits_1[idx_y], y_bits_1[idx_y - z]
-                    );
-                    y_bits_0.set(idx_y - z, or_0);
-                    y_bits_1.set(idx_y - z, or_1);
-                }
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+// Path of party `id`'s preprocessing file.
+fn prep_path(prep: &str, id: usize) -> String {
+    format!("{}.p{}", prep, id)
+}
+
+// Offline phase of `n` field comparisons with OT-based gates.
+fn run_offline(n: usize, prep: &str) -> Result<()> {
+    let mut batch = Batch::new();
+    batch.lt_const_field(GateKind::Ot, n);
+    for (id, pre) in batch.deal(&mut rand::thread_rng()).iter().enumerate() {
+        pre.write(prep_path(prep, id))?;
+        println!("P{} preprocessing written to {}", id, prep_path(prep, id));
+    }
+    Ok(())
+}
+
+// Runs one party over TCP: party 0 owns the secret inputs x, party 1 owns
+// the public constants R, and both learn [x <= R].
+//
+// The correlated randomness comes from the party's preprocessing file, from
+// a dealer server, or from a dealer simulated from the common `--seed`,
+// which is for testing only.
+fn run_party(args: &Args) -> Result<()> {
+    let dealer: Box<dyn Dealer> = match &args.prep {
+        Some(prep) => {
+            let pre = Preprocessing::read(prep_path(prep, args.party))?;
+            if pre.party() != args.party {
+                return Err(Error::Protocol("preprocessing file of the other party"));
             }
+            Box::new(pre)
+        }
+        None => match (&args.dealer, args.seed) {
+            (Some(addr), _) => Box::new(RemoteDealer::connect(addr, args.party)?),
+            (None, Some(seed)) => {
+                eprintln!("warning: the dealer simulated from --seed reveals each party's randomness to the other, for testing only");
+                Box::new(SeededDealer::new(args.party, seed))
+            }
+            (None, None) => unreachable!("parse_args requires a source of randomness"),
+        },
+    };
+    // with an OT pool, its worker gets a second connection
+    let connections = if args.ot_source == OtSource::Pool { 2 } else { 1 };
+    let mut chans = Vec::new();
+    if args.party == 0 {
+        let listener = TcpListener::bind(&args.peer)?;
+        for _ in 0..connections {
+            chans.push(TcpChannel::accept(&listener)?);
+        }
+    } else {
+        for _ in 0..connections {
+            chans.push(TcpChannel::connect(&args.peer, 20)?);
         }
     }
-    y_bits_0.push(false);
-    y_bits_1.push(false);
-    let z_bits_0 = y_bits_0;
-    let z_bits_1 = y_bits_1;
-
-    // Step 3
-    let mut w_bits_0 = bitvec![u8, Lsb0; 0; gates::M];
-    let mut w_bits_1 = bitvec![u8, Lsb0; 0; gates::M];
-    for i in 0..gates::M {
-        w_bits_0.set(i, z_bits_0[i] ^ z_bits_0[i+1]); // -
-        w_bits_1.set(i, z_bits_1[i] ^ z_bits_1[i+1]); // -
+    let mut p = Party::new(args.party, Box::new(chans.remove(0)), dealer);
+    p.edabit_source = args.edabit_source;
+    p.ot_source = args.ot_source;
+    if let Some(chan) = chans.pop() {
+        let worker = Party::new(args.party, Box::new(chan), Box::new(NoDealer));
+        p.ot_pool = Some(OtPool::spawn(worker, POOL_BATCH, POOL_CAPACITY));
     }
+    let mut rng = rand::thread_rng();
+    let start = Instant::now();
 
-    // Step 4
-    let mut sum_0 = 0u8;
-    let mut sum_1 = 0u8;
-    for i in 0..gates::M {
-        sum_0 += if r_bits[i] & w_bits_0[i] { 1 } else { 0 };
-        sum_1 += if r_bits[i] & w_bits_1[i] { 1 } else { 0 };
+    for i in 0..ITER {
+        // LT Const over the whole field: [x <= R]
+        let (x, x_sh) = if p.id == 0 {
+            let x = FE::random();
+            (Some(x.value()), p.share_input(&x)?)
+        } else {
+            (None, p.recv_input()?)
+        };
+        let const_r = if p.id == 1 {
+            let const_r = rng.gen_range(0..PRIME_ORDER);
+            p.send(&const_r)?;
+            const_r
+        } else {
+            p.recv()?
+        };
+        let w = lt_const_field(&mut p, const_r, x_sh)?;
+        let lt = p.open_bit(w != 0)?;
+        if let Some(x) = x {
+            assert_eq!(lt, x <= const_r, "LT Const (field): {} <= {}", x, const_r);
+        }
+        println!("LT Const (field) {}) x <= {}: {}", i, const_r, lt as u8);
     }
-
-    (1 - sum_0.view_bits::<Lsb0>().to_bitvec()[0] as u8, 
-    sum_1.view_bits::<Lsb0>().to_bitvec()[0] as u8)
+    println!("LT Const (field, {} bits): {:?} per comparison", N_BITS, start.elapsed() / ITER as u32);
+    print!("P{} communication, {}", p.id, p.stats);
+    if let Some(pool) = p.ot_pool.take() {
+        println!("OT pool: {} OTs per comparison", pool.consumed() / ITER as u64);
+        print!("P{} OT pool communication, {}", p.id, pool.stop()?);
+    }
+    p.close()
 }
 
-// Returns c = x <= R
-fn lt_const(const_r: u8, x_0: FE, x_1: FE) -> (u8, u8) {
-    let ((r_0, r_0_bits), (r_1, r_1_bits)) = get_rand_edabit();
-    let const_m = 1 << 8;
-
-    debug_println!("Params:");
-    debug_println!("\tR: {}", const_r);
-    debug_println!("\tM: {}", const_m);
-    let mut r: FE = Group::zero();
-    r.add(&r_0);
-    r.add(&r_1);
-    debug_println!("\trandom r for edabit: {}", r.value());
-
-    // Step 1
-    let mut a_0: FE = Group::zero();
-    a_0.add(&x_0);
-    a_0.add(&r_0);
-
-    let mut a_1: FE = Group::zero();
-    a_1.add(&x_1);
-    a_1.add(&r_1);
-    
-    let b_0 = a_0.clone();
-    let mut b_1 = a_1.clone();
-    let const_r_fe = FE::new(const_m - const_r as u64);
-    b_1.add(&const_r_fe);
-
-    // Step 2
-    let mut a: FE = Group::zero();
-    a.add(&a_0);
-    a.add(&a_1);
-
-    let mut b: FE = Group::zero();
-    b.add(&b_0);
-    b.add(&b_1);
-
-    debug_println!("Steps 1 and 2 (compute a and b and open them):");
-    debug_println!("\ta (= x + r): {}", a.value() as u8);
-    debug_println!("\tb (= x + r + M - R): {}", b.value() as u8);
-
-    // Step 3
-    let (w1_0, w1_1) = lt_bits(a.value() as u8, &r_0_bits, &r_1_bits);
-    let (w2_0, w2_1) = lt_bits(b.value() as u8, &r_0_bits, &r_1_bits);
-    let w3 = ((b.value() as u8) < (const_m - const_r as u64) as u8) as u8;
-    
-    debug_println!("Step 3:");
-    debug_println!("\tw1 (LTbits(a <= r) -- LTbits({} <= {})): {}", a.value() as u8, r.value(), w1_0 ^ w1_1);
-    debug_println!("\tw2 (LTbits(b <= r) -- LTbits({} <= {})): {}", b.value() as u8, r.value(), w2_0 ^ w2_1);
-    debug_println!("\tw3 ((b < M - R) -- {} < {}): {}", b.value() as u8, (const_m - const_r as u64) as u8, w3);
-    
-    // Step 4
-    let w_0 = 1 - (w1_0 ^ w2_0 ^ w3);
-    let w_1 = w1_1 ^ w2_1;
-    
-    debug_println!("Step 4:");   
-    debug_println!("\tw (1 - (w1 - w2 + w3)): {}", w_0 ^ w_1);
-    debug_println!("\tx < {} : {}", const_r, w_0 ^ w_1 != 0);
-
-    (w_0, w_1)
+// Benchmarks LTBits with OTs from the IKNP extension against OTs from the
+// dealer, checking the results on the way.
+fn bench_iknp() {
+    let mut rng = rand::thread_rng();
+    // enough comparisons to amortise the base OTs over a few batches
+    const BENCH: usize = 10 * ITER;
+    let inputs: Vec<(u64, [BitVec<u8>; 2])> = (0..BENCH)
+        .map(|_| {
+            let (x0, x1) = gates::secret_share(&gates::to_bits(rng.gen_range(0..(1 << K)), K));
+            (rng.gen_range(0..(1 << K)), [x0, x1])
+        })
+        .collect();
+    for source in [OtSource::Dealer, OtSource::Iknp] {
+        let ((w_0, elapsed, ots), (w_1, _, _)) = run_metered(GateKind::Ot, &mut Default::default(), |p| {
+            p.ot_source = source;
+            let start = Instant::now();
+            let w = inputs.iter().map(|(r, x_sh)| lt_bits::<K>(p, *r, &x_sh[p.id])).collect::<Result<Vec<_>>>()?;
+            Ok((w, start.elapsed(), p.stats.op("one_out_of_two_ot").calls))
+        });
+        for (((r, x_sh), w_0), w_1) in inputs.iter().zip(w_0).zip(w_1) {
+            let x = gates::reconstruct_shares(&x_sh[0], &x_sh[1]).load_le::<u64>();
+            assert_eq!(w_0 ^ w_1 != 0, *r <= x, "LT Bits ({:?} OTs): {} <= {}", source, r, x);
+        }
+        println!(
+            "LT Bits ({:?} OTs): {} comparisons, {} OTs and {:?} per comparison",
+            source, BENCH, ots / BENCH as u64, elapsed / BENCH as u32
+        );
+    }
 }
 
 fn main() {
+    match parse_args() {
+        Mode::InMemory => {}
+        Mode::Offline { n, prep } => {
+            if let Err(e) = run_offline(n, &prep) {
+                eprintln!("offline: {}", e);
+                process::exit(1);
+            }
+            return;
+        }
+        Mode::Party(args) => {
+            if let Err(e) = run_party(&args) {
+                eprintln!("party {}: {}", args.party, e);
+                process::exit(1);
+            }
+            return;
+        }
+    }
+
     debug_println!("[LSB, ..., MSB]\n");
-    const R: u8 = 1; // public const
+    for kind in [GateKind::Ot, GateKind::Triple] {
+        compare_gates(kind);
+    }
+    bench_iknp();
+    let kind = GateKind::Ot;
     let mut rng = rand::thread_rng();
+    let mut field_time = Duration::ZERO;
+    let mut ring_time = Duration::ZERO;
+    let mut stats: [Stats; 2] = Default::default();
+
+    for i in 0..ITER {
+        let const_r: u64 = rng.gen_range(0..(1 << K)); // public const
+        let x: u64 = rng.gen_range(0..(1 << K));
+        let (x_0, x_1) = FE::new(x).share();
+        let x_fe = [x_0, x_1];
+        println!("input x = {}", x);
 
-    for i in 0..gates::ITER {
-        let x = rng.gen_range(1..255);
-        let x_bits = x.view_bits::<Lsb0>().to_bitvec();
-        let (x0, x1) = gates::secret_share(&x_bits);
+        // A2B: the bits of x, from its FE shares
+        let (x0, x1) = run_metered(kind, &mut stats, |p| a2b_bits::<K>(p, x_fe[p.id]));
+        assert_eq!(gates::reconstruct_shares(&x0, &x1).load_le::<u64>(), x, "A2B: {}", x);
+        let x_sh = [x0, x1];
 
-        // LT Bits: [R <= x]
-        let (sum_0, sum_1) = lt_bits(R, &x0, &x1);
+        // LT Bits: [x >= R]
+        let (sum_0, sum_1) = run_metered(kind, &mut stats, |p| compare_bits::<K>(p, CmpOp::Ge, const_r, &x_sh[p.id]));
         let lt = sum_0 ^ sum_1;
-        assert_eq!(lt != 0, R <= x, "LT Bits: {} <= {}", R, x);
-        println!("LT Bits {}) {} <= {}: {} (expected: {})", i, R, x, lt, R <= x);
+        assert_eq!(lt != 0, CmpOp::Ge.eval(x, const_r), "LT Bits: {} >= {}", x, const_r);
+        println!("LT Bits {}) {} >= {}: {} (expected: {})", i, x, const_r, lt, x >= const_r);
 
-        let (x_0, x_1) = FE::new(x as u64).share();
-        println!("input x = {}", x);
-        
-        // LT Const: [x < R]
-        let (w_0, w_1) = lt_const(R, x_0, x_1);
+        // LT Bits (secret): [y <= x]
+        let y: u64 = rng.gen_range(0..(1 << K));
+        let (y0, y1) = gates::secret_share(&gates::to_bits(y, K));
+        let y_sh = [y0, y1];
+        let (sum_0, sum_1) = run_metered(kind, &mut stats, |p| lt_bits_secret::<K>(p, &y_sh[p.id], &x_sh[p.id]));
+        let lt = sum_0 ^ sum_1;
+        assert_eq!(lt != 0, y <= x, "LT Bits (secret): {} <= {}", y, x);
+        println!("LT Bits (secret) {}) {} <= {}: {} (expected: {})", i, y, x, lt, y <= x);
+
+        // LT Const: [x <= R], on the same FE shares of x
+        let (w_0, w_1) = run_metered(kind, &mut stats, |p| compare_const::<K>(p, CmpOp::Le, const_r, x_fe[p.id]));
         let lt = w_0 ^ w_1;
-        assert_eq!(lt != 0, x <= R, "LT Const: {} <= {}", x, R);
-        println!("LT Const {}) {} <= {}: {} (expected: {})", i, x, R, lt, x <= R);
+        assert_eq!(lt != 0, CmpOp::Le.eval(x, const_r), "LT Const: {} <= {}", x, const_r);
+        println!("LT Const {}) {} <= {}: {} (expected: {})", i, x, const_r, lt, x <= const_r);
+
+        // EQ Const: [x == R]
+        let (w_0, w_1) = run_metered(kind, &mut stats, |p| eq_const(p, const_r, x_fe[p.id]));
+        let eq = w_0 ^ w_1;
+        assert_eq!(eq != 0, x == const_r, "EQ Const: {} == {}", x, const_r);
+        println!("EQ Const {}) {} == {}: {} (expected: {})", i, x, const_r, eq, x == const_r);
+
+        // LT Const over the whole field: [x <= R]
+        let const_r = FE::random().value();
+        let x = FE::random();
+        let (x_0, x_1) = x.share();
+        let x_sh = [x_0, x_1];
+        let ((w_0, elapsed), (w_1, _)) = run_metered(kind, &mut stats, |p| {
+            let start = Instant::now();
+            let w = compare_const_field(p, CmpOp::Le, const_r, x_sh[p.id])?;
+            Ok((w, start.elapsed()))
+        });
+        field_time += elapsed;
+        let lt = w_0 ^ w_1;
+        let x = x.value();
+        assert_eq!(lt != 0, CmpOp::Le.eval(x, const_r), "LT Const (field): {} <= {}", x, const_r);
+        println!("LT Const (field) {}) {} <= {}: {} (expected: {})", i, x, const_r, lt, x <= const_r);
+
+        // LT Const over the ring Z_{2^RING_K}: [x <= R]
+        let const_r = Z2k::<RING_K>::random().value();
+        let x = Z2k::<RING_K>::random();
+        let (x_0, x_1) = x.share();
+        let x_sh = [x_0, x_1];
+        let ((w_0, elapsed), (w_1, _)) = run_metered(kind, &mut stats, |p| {
+            let start = Instant::now();
+            let w = compare_const_ring(p, CmpOp::Le, const_r, x_sh[p.id])?;
+            Ok((w, start.elapsed()))
+        });
+        ring_time += elapsed;
+        let lt = w_0 ^ w_1;
+        let x = x.value();
+        assert_eq!(lt != 0, CmpOp::Le.eval(x, const_r), "LT Const (ring): {} <= {}", x, const_r);
+        println!("LT Const (ring) {}) {} <= {}: {} (expected: {})", i, x, const_r, lt, x <= const_r);
+
+        // MUL: [x * y]
+        let x = FE::random();
+        let y = FE::random();
+        let (x_0, x_1) = x.share();
+        let (y_0, y_1) = y.share();
+        let (x_sh, y_sh) = ([x_0, x_1], [y_0, y_1]);
+        let (mut z, z_1) = run_metered(kind, &mut stats, |p| mul(p, x_sh[p.id], y_sh[p.id]));
+        z.add(&z_1);
+        assert_eq!(z, x * y, "MUL: {} * {}", x, y);
+        println!("MUL {}) {} * {}: {} (expected: {})", i, x, y, z, x * y);
+
+        // LT Secret: [x < y]
+        let x = rng.gen_range(0..=SECRET_CMP_MAX);
+        let y = rng.gen_range(0..=SECRET_CMP_MAX);
+        let (x_0, x_1) = FE::new(x).share();
+        let (y_0, y_1) = FE::new(y).share();
+        let (x_sh, y_sh) = ([x_0, x_1], [y_0, y_1]);
+        let (c_0, c_1) = run_metered(kind, &mut stats, |p| lt_secret(p, x_sh[p.id], y_sh[p.id]));
+        let lt = c_0 ^ c_1;
+        assert_eq!(lt != 0, x < y, "LT Secret: {} < {}", x, y);
+        println!("LT Secret {}) {} < {}: {} (expected: {})", i, x, y, lt, x < y);
+
+        let (mut c, c_1) = run_metered(kind, &mut stats, |p| lt_secret_fe(p, x_sh[p.id], y_sh[p.id]));
+        c.add(&c_1);
+        assert_eq!(c.value(), (x < y) as u64, "LT Secret (FE): {} < {}", x, y);
+
+        // Sign: [x < 0], for a signed x centred in FE
+        let x = rng.gen_range(-FE_SIGNED_MAX..=FE_SIGNED_MAX);
+        let (x_0, x_1) = signed::fe_from_signed(x).share();
+        let x_sh = [x_0, x_1];
+        let (s_0, s_1) = run_metered(kind, &mut stats, |p| sign_field(p, x_sh[p.id]));
+        let neg = s_0 ^ s_1;
+        assert_eq!(neg != 0, x < 0, "Sign: {} < 0", x);
+        println!("Sign {}) {} < 0: {} (expected: {})", i, x, neg, x < 0);
         println!();
     }
 
+    println!("LT Const (field, {} bits): {:?} per comparison", N_BITS, field_time / ITER as u32);
+    println!("LT Const (ring, {} bits): {:?} per comparison", RING_K, ring_time / ITER as u32);
+    for (id, stats) in stats.iter().enumerate() {
+        print!("P{} communication, {}", id, stats);
+    }
 }
@@ -0,0 +1,550 @@
+// Oblivious transfer between the two parties, without a trusted party.
+//
+// Base OTs follow Chou and Orlandi, "The Simplest Protocol for Oblivious
+// Transfer" (eprint 2015/267), over the Ristretto group: the sender publishes
+// A = aG, the receiver answers B = bG + cA for its choice c, and the keys
+// are k_0 = H(aB), k_1 = H(a(B - A)) on one side and k_c = H(bA) on the
+// other.
+//
+// Large numbers of OTs are extended from KAPPA base OTs with IKNP (Ishai,
+// Kilian, Nissim and Petrank, "Extending Oblivious Transfers Efficiently",
+// CRYPTO 2003), with the roles of the base OTs reversed. Extended random OTs
+// are derandomised into correlated OTs over FE, the building block of
+// Gilboa's product sharing in `beaver`.
+
+use bitvec::prelude::*;
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::dealer::{OtReceiver, OtSender};
+use crate::error::{Error, Result};
+use crate::fastfield::{FE, PRIME_ORDER};
+use crate::party::Party;
+use crate::stats::Stats;
+
+/// Key of one random OT: 128 bits.
+pub type Block = [u8; 16];
+
+/// Where [`one_out_of_two_ot`](crate::gates::one_out_of_two_ot) gets its
+/// random OT correlations from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtSource {
+    /// The trusted dealer.
+    Dealer,
+    /// A fresh Chou–Orlandi base OT for every OT, no trusted party involved.
+    BaseOt,
+    /// IKNP OT extension, in batches of [`IKNP_BATCH`] OTs on top of
+    /// [`KAPPA`] base OTs per direction.
+    Iknp,
+    /// The random OTs pre-generated by `p.ot_pool`.
+    Pool,
+}
+
+/// Security parameter of the OT extension: number of base OTs.
+pub const KAPPA: usize = 128;
+/// OTs extended at once when the [`Extension`] buffer runs empty.
+pub const IKNP_BATCH: usize = 1 << 14;
+
+// Key of the i-th OT, derived from the shared point and the transcript.
+fn hash_key(i: usize, a: &[u8; 32], b: &[u8; 32], shared: &RistrettoPoint) -> Block {
+    let mut h = Sha256::new();
+    h.update(b"rabbit-mpc base OT");
+    h.update((i as u64).to_le_bytes());
+    h.update(a);
+    h.update(b);
+    h.update(shared.compress().as_bytes());
+    let mut key = [0; 16];
+    key.copy_from_slice(&h.finalize()[..16]);
+    key
+}
+
+fn decompress(bytes: &[u8; 32]) -> Result<RistrettoPoint> {
+    CompressedRistretto(*bytes)
+        .decompress()
+        .ok_or(Error::Protocol("invalid group element"))
+}
+
+/// Sender side of `n` random base OTs. Returns the two random keys of each.
+pub fn random_ot_send(p: &mut Party, n: usize) -> Result<Vec<[Block; 2]>> {
+    p.metered("base_ot", |p| {
+        let a = Scalar::random(&mut rand::thread_rng());
+        let big_a = RistrettoPoint::mul_base(&a);
+        let a_bytes = big_a.compress().to_bytes();
+        p.send(&a_bytes)?;
+
+        let bs: Vec<[u8; 32]> = p.recv()?;
+        if bs.len() != n {
+            return Err(Error::Protocol("wrong number of base OT answers"));
+        }
+        bs.iter()
+            .enumerate()
+            .map(|(i, b_bytes)| {
+                let big_b = decompress(b_bytes)?;
+                let k_0 = hash_key(i, &a_bytes, b_bytes, &(a * big_b));
+                let k_1 = hash_key(i, &a_bytes, b_bytes, &(a * (big_b - big_a)));
+                Ok([k_0, k_1])
+            })
+            .collect()
+    })
+}
+
+/// Receiver side of random base OTs, one per choice bit. Returns the key
+/// `k_c` of each.
+pub fn random_ot_recv(p: &mut Party, choices: &[bool]) -> Result<Vec<Block>> {
+    p.metered("base_ot", |p| {
+        let a_bytes: [u8; 32] = p.recv()?;
+        let big_a = decompress(&a_bytes)?;
+
+        let mut rng = rand::thread_rng();
+        let bs: Vec<Scalar> = choices.iter().map(|_| Scalar::random(&mut rng)).collect();
+        let b_bytes: Vec<[u8; 32]> = bs
+            .iter()
+            .zip(choices)
+            .map(|(b, &c)| {
+                let big_b = RistrettoPoint::mul_base(b);
+                let big_b = if c { big_b + big_a } else { big_b };
+                big_b.compress().to_bytes()
+            })
+            .collect();
+        p.send(&b_bytes)?;
+
+        Ok(bs
+            .iter()
+            .zip(&b_bytes)
+            .enumerate()
+            .map(|(i, (b, b_bytes))| hash_key(i, &a_bytes, b_bytes, &(b * big_a)))
+            .collect())
+    })
+}
+
+// Random OT correlations on single bits, from the keys of random OTs.
+fn sender_half([k_0, k_1]: [Block; 2]) -> OtSender {
+    OtSender { k: [k_0[0] & 1, k_1[0] & 1] }
+}
+
+fn receiver_half(k_c: Block, c: bool) -> OtReceiver {
+    OtReceiver { c: c as u8, kc: k_c[0] & 1 }
+}
+
+fn random_choices(n: usize) -> Vec<bool> {
+    let mut rng = rand::thread_rng();
+    (0..n).map(|_| rng.gen()).collect()
+}
+
+/// This party's sender half of the next random OT correlation, in which it
+/// is the sender, from `p.ot_source`.
+pub fn sender_correlation(p: &mut Party) -> Result<OtSender> {
+    match p.ot_source {
+        OtSource::Dealer => p.dealer.ot_sender(p.id),
+        OtSource::BaseOt => Ok(sender_half(random_ot_send(p, 1)?[0])),
+        OtSource::Iknp => {
+            if p.ot_ext.sent.is_empty() {
+                let keys = extend_send(p, IKNP_BATCH)?;
+                p.ot_ext.sent.extend(keys.into_iter().map(sender_half));
+            }
+            Ok(p.ot_ext.sent.pop_front().expect("extended OTs"))
+        }
+        OtSource::Pool => pool(p)?.take_sent(),
+    }
+}
+
+/// This party's receiver half of the next random OT correlation, in which
+/// the other party is the sender, from `p.ot_source`.
+pub fn receiver_correlation(p: &mut Party) -> Result<OtReceiver> {
+    match p.ot_source {
+        OtSource::Dealer => p.dealer.ot_receiver(p.peer()),
+        OtSource::BaseOt => {
+            let c = rand::random::<bool>();
+            Ok(receiver_half(random_ot_recv(p, &[c])?[0], c))
+        }
+        OtSource::Iknp => {
+            if p.ot_ext.received.is_empty() {
+                let choices = random_choices(IKNP_BATCH);
+                let keys = extend_recv(p, &choices)?;
+                p.ot_ext.received.extend(keys.into_iter().zip(choices).map(|(k_c, c)| receiver_half(k_c, c)));
+            }
+            Ok(p.ot_ext.received.pop_front().expect("extended OTs"))
+        }
+        OtSource::Pool => pool(p)?.take_received(),
+    }
+}
+
+fn pool(p: &Party) -> Result<&OtPool> {
+    p.ot_pool.as_ref().ok_or(Error::Protocol("OtSource::Pool needs an OT pool"))
+}
+
+/// Extends `m` random OTs in which this party is the sender with the IKNP
+/// state in `p.ot_ext`, setting it up on first use. Returns the two random
+/// keys of each.
+pub fn extend_send(p: &mut Party, m: usize) -> Result<Vec<[Block; 2]>> {
+    let mut ext = match p.ot_ext.sender.take() {
+        Some(ext) => ext,
+        None => IknpSender::setup(p)?,
+    };
+    let keys = ext.extend(p, m);
+    p.ot_ext.sender = Some(ext);
+    keys
+}
+
+/// Extends one random OT per choice bit, in which the other party is the
+/// sender, with the IKNP state in `p.ot_ext`. Returns the key `k_c` of each.
+pub fn extend_recv(p: &mut Party, choices: &[bool]) -> Result<Vec<Block>> {
+    let mut ext = match p.ot_ext.receiver.take() {
+        Some(ext) => ext,
+        None => IknpReceiver::setup(p)?,
+    };
+    let keys = ext.extend(p, choices);
+    p.ot_ext.receiver = Some(ext);
+    keys
+}
+
+// Maps an OT key to an FE, uniform up to a statistical distance of 2^-86.
+fn key_to_fe(k: &Block) -> FE {
+    FE::new((u128::from_le_bytes(*k) % PRIME_ORDER as u128) as u64)
+}
+
+/// Sender side of correlated OTs over `FE`, one per `delta`: the receiver
+/// gets `s + b * delta` for its choice bit `b`. Returns the `s` of each.
+//
+// Derandomised from extended random OTs (k_0, k_1): the receiver sends
+// z = b ^ c for its random choice c, the sender keeps s = k_z and sends
+// d = k_z + delta - k_(1 - z), which the receiver adds to k_c = k_(1 - z)
+// when b = 1. With b = 0 the receiver holds k_z and d stays masked.
+pub fn cot_send(p: &mut Party, deltas: &[FE]) -> Result<Vec<FE>> {
+    p.metered("cot", |p| {
+        let keys = extend_send(p, deltas.len())?;
+        let zs: BitVec<u8> = p.recv()?;
+        if zs.len() != deltas.len() {
+            return Err(Error::Protocol("wrong number of correlated OT choices"));
+        }
+        let (s, d): (Vec<FE>, Vec<FE>) = keys
+            .iter()
+            .zip(zs.iter().by_vals())
+            .zip(deltas)
+            .map(|(([k_0, k_1], z), &delta)| {
+                let (k_z, k_other) = if z { (k_1, k_0) } else { (k_0, k_1) };
+                let s = key_to_fe(k_z);
+                (s, s + delta - key_to_fe(k_other))
+            })
+            .unzip();
+        p.send(&d)?;
+        Ok(s)
+    })
+}
+
+/// Receiver side of correlated OTs over `FE`, one per choice bit `b`.
+/// Returns `s + b * delta` of each.
+pub fn cot_recv(p: &mut Party, choices: &[bool]) -> Result<Vec<FE>> {
+    p.metered("cot", |p| {
+        let cs = random_choices(choices.len());
+        let keys = extend_recv(p, &cs)?;
+        let zs: BitVec<u8> = choices.iter().zip(&cs).map(|(&b, &c)| b ^ c).collect();
+        p.send(&zs)?;
+        let ds: Vec<FE> = p.recv()?;
+        if ds.len() != choices.len() {
+            return Err(Error::Protocol("wrong number of correlated OT corrections"));
+        }
+        Ok(keys
+            .iter()
+            .zip(choices)
+            .zip(ds)
+            .map(|((k_c, &b), d)| if b { key_to_fe(k_c) + d } else { key_to_fe(k_c) })
+            .collect())
+    })
+}
+
+/// One party's IKNP state and buffered random OTs of both directions, for
+/// [`OtSource::Iknp`].
+#[derive(Default)]
+pub struct Extension {
+    sender: Option<IknpSender>,
+    receiver: Option<IknpReceiver>,
+    sent: VecDeque<OtSender>,
+    received: VecDeque<OtReceiver>,
+}
+
+// PRG stretching a base OT key into a column of the IKNP matrix.
+fn prg(key: &Block) -> ChaCha12Rng {
+    let mut seed = [0; 32];
+    seed[..16].copy_from_slice(key);
+    ChaCha12Rng::from_seed(seed)
+}
+
+// Correlation-robust hash of the j-th row of the IKNP matrix.
+fn hash_row(j: u64, row: u128) -> Block {
+    let mut h = Sha256::new();
+    h.update(b"rabbit-mpc IKNP");
+    h.update(j.to_le_bytes());
+    h.update(row.to_le_bytes());
+    let mut key = [0; 16];
+    key.copy_from_slice(&h.finalize()[..16]);
+    key
+}
+
+// Transposes KAPPA columns of m bits into m rows of KAPPA bits.
+fn transpose(cols: &[Vec<u8>], m: usize) -> Vec<u128> {
+    let mut rows = vec![0u128; m];
+    for (i, col) in cols.iter().enumerate() {
+        for (j, row) in rows.iter_mut().enumerate() {
+            *row |= (((col[j / 8] >> (j % 8)) & 1) as u128) << i;
+        }
+    }
+    rows
+}
+
+/// Sender side of the IKNP OT extension: the base OT receiver.
+pub struct IknpSender {
+    // the base OT choices
+    s: u128,
+    // PRGs seeded with k_i^{s_i}
+    prgs: Vec<ChaCha12Rng>,
+    // OTs extended so far, for the hash index
+    count: u64,
+}
+
+impl IknpSender {
+    /// Runs the [`KAPPA`] base OTs with the other party's
+    /// [`IknpReceiver::setup`].
+    pub fn setup(p: &mut Party) -> Result<IknpSender> {
+        let s: u128 = rand::random();
+        let choices: Vec<bool> = (0..KAPPA).map(|i| (s >> i) & 1 == 1).collect();
+        let keys = random_ot_recv(p, &choices)?;
+        Ok(IknpSender { s, prgs: keys.iter().map(prg).collect(), count: 0 })
+    }
+
+    /// Extends `m` random OTs. Returns the two random keys of each.
+    pub fn extend(&mut self, p: &mut Party, m: usize) -> Result<Vec<[Block; 2]>> {
+        p.metered("iknp", |p| {
+            let len = m.div_ceil(8);
+            let us: Vec<Vec<u8>> = p.recv()?;
+            if us.len() != KAPPA || us.iter().any(|u| u.len() != len) {
+                return Err(Error::Protocol("malformed OT extension message"));
+            }
+            // q_i = G(k_i^{s_i}) ^ s_i * u_i, so that row q_j = t_j ^ r_j * s
+            let qs: Vec<Vec<u8>> = self.prgs.iter_mut().zip(us).enumerate().map(|(i, (prg, u))| {
+                let mut q = vec![0; len];
+                prg.fill_bytes(&mut q);
+                if (self.s >> i) & 1 == 1 {
+                    q.iter_mut().zip(u).for_each(|(q, u)| *q ^= u);
+                }
+                q
+            }).collect();
+
+            let start = self.count;
+            self.count += m as u64;
+            Ok(transpose(&qs, m)
+                .into_iter()
+                .enumerate()
+                .map(|(j, q)| {
+                    let j = start + j as u64;
+                    [hash_row(j, q), hash_row(j, q ^ self.s)]
+                })
+                .collect())
+        })
+    }
+}
+
+/// Receiver side of the IKNP OT extension: the base OT sender.
+pub struct IknpReceiver {
+    // PRGs seeded with (k_i^0, k_i^1)
+    prgs: Vec<[ChaCha12Rng; 2]>,
+    count: u64,
+}
+
+impl IknpReceiver {
+    /// Runs the [`KAPPA`] base OTs with the other party's
+    /// [`IknpSender::setup`].
+    pub fn setup(p: &mut Party) -> Result<IknpReceiver> {
+        let keys = random_ot_send(p, KAPPA)?;
+        Ok(IknpReceiver { prgs: keys.iter().map(|[k_0, k_1]| [prg(k_0), prg(k_1)]).collect(), count: 0 })
+    }
+
+    /// Extends one random OT per choice bit. Returns the key `k_c` of each.
+    pub fn extend(&mut self, p: &mut Party, choices: &[bool]) -> Result<Vec<Block>> {
+        p.metered("iknp", |p| {
+            let m = choices.len();
+            let len = m.div_ceil(8);
+            let mut r = vec![0u8; len];
+            for (j, &c) in choices.iter().enumerate() {
+                r[j / 8] |= (c as u8) << (j % 8);
+            }
+            // t_i = G(k_i^0), u_i = t_i ^ G(k_i^1) ^ r
+            let mut ts = Vec::with_capacity(KAPPA);
+            let mut us = Vec::with_capacity(KAPPA);
+            for [prg_0, prg_1] in self.prgs.iter_mut() {
+                let mut t = vec![0; len];
+                let mut u = vec![0; len];
+                prg_0.fill_bytes(&mut t);
+                prg_1.fill_bytes(&mut u);
+                u.iter_mut().zip(&t).zip(&r).for_each(|((u, t), r)| *u ^= t ^ r);
+                ts.push(t);
+                us.push(u);
+            }
+            p.send(&us)?;
+
+            let start = self.count;
+            self.count += m as u64;
+            Ok(transpose(&ts, m)
+                .into_iter()
+                .enumerate()
+                .map(|(j, t)| hash_row(start + j as u64, t))
+                .collect())
+        })
+    }
+}
+
+/// Pool of random OTs of both directions pre-generated for a session, for
+/// [`OtSource::Pool`].
+///
+/// A background worker extends the OTs with IKNP over its own channel to
+/// the other party's worker, refilling the pool whenever it holds less than
+/// its capacity. The OTs are handed out in the order they were generated,
+/// which is the same on both sides.
+pub struct OtPool {
+    shared: Arc<Shared>,
+    worker: Option<JoinHandle<Result<Stats>>>,
+}
+
+struct Shared {
+    state: Mutex<PoolState>,
+    cond: Condvar,
+}
+
+#[derive(Default)]
+struct PoolState {
+    sent: VecDeque<OtSender>,
+    received: VecDeque<OtReceiver>,
+    consumed: u64,
+    // set by the owner to stop the worker
+    stop: bool,
+    // set by the worker when it exits
+    done: bool,
+}
+
+impl OtPool {
+    /// Starts the worker on `worker`, a party connected to the other party's
+    /// worker by a channel of its own. The worker extends `batch` OTs of
+    /// each direction at a time, up to `capacity` OTs.
+    pub fn spawn(worker: Party, batch: usize, capacity: usize) -> OtPool {
+        let shared = Arc::new(Shared { state: Mutex::new(PoolState::default()), cond: Condvar::new() });
+        let worker = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || run_worker(worker, &shared, batch, capacity))
+        };
+        OtPool { shared, worker: Some(worker) }
+    }
+
+    /// Number of OTs handed out so far, of both directions.
+    pub fn consumed(&self) -> u64 {
+        self.lock().consumed
+    }
+
+    /// Number of OTs of both directions ready to be handed out.
+    pub fn available(&self) -> usize {
+        let state = self.lock();
+        state.sent.len().min(state.received.len())
+    }
+
+    /// Stops the worker and waits for it. Returns the communication it sent.
+    pub fn stop(mut self) -> Result<Stats> {
+        self.signal_stop();
+        self.worker.take().expect("worker is running").join().expect("OT pool worker panicked")
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState> {
+        self.shared.state.lock().expect("OT pool lock poisoned")
+    }
+
+    fn signal_stop(&self) {
+        self.lock().stop = true;
+        self.shared.cond.notify_all();
+    }
+
+    // Waits until `take` finds an OT, or the worker is gone.
+    fn take<T>(&self, take: impl Fn(&mut PoolState) -> Option<T>) -> Result<T> {
+        let mut state = self.lock();
+        loop {
+            if let Some(ot) = take(&mut state) {
+                state.consumed += 1;
+                // wake the worker up to refill
+                self.shared.cond.notify_all();
+                return Ok(ot);
+            }
+            if state.done {
+                return Err(Error::Protocol("the OT pool worker stopped"));
+            }
+            state = self.shared.cond.wait(state).expect("OT pool lock poisoned");
+        }
+    }
+
+    fn take_sent(&self) -> Result<OtSender> {
+        self.take(|state| state.sent.pop_front())
+    }
+
+    fn take_received(&self) -> Result<OtReceiver> {
+        self.take(|state| state.received.pop_front())
+    }
+}
+
+impl Drop for OtPool {
+    // The worker exits on its own once it sees the flag, or once the other
+    // party's worker hangs up.
+    fn drop(&mut self) {
+        self.signal_stop();
+    }
+}
+
+fn run_worker(mut p: Party, shared: &Shared, batch: usize, capacity: usize) -> Result<Stats> {
+    let out = fill(&mut p, shared, batch, capacity);
+    shared.state.lock().expect("OT pool lock poisoned").done = true;
+    shared.cond.notify_all();
+    match out {
+        // the other party's worker stopping first is a normal end
+        Ok(()) | Err(Error::Disconnected) => {
+            let _ = p.close();
+            Ok(p.stats)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+// P0 is the IKNP sender of its own OTs first, then the receiver of P1's;
+// P1 mirrors it, so that both workers always run the same extension.
+fn fill(p: &mut Party, shared: &Shared, batch: usize, capacity: usize) -> Result<()> {
+    let (mut sender, mut receiver) = if p.id == 0 {
+        let sender = IknpSender::setup(p)?;
+        (sender, IknpReceiver::setup(p)?)
+    } else {
+        let receiver = IknpReceiver::setup(p)?;
+        (IknpSender::setup(p)?, receiver)
+    };
+    loop {
+        {
+            let mut state = shared.state.lock().expect("OT pool lock poisoned");
+            while !state.stop && state.sent.len().min(state.received.len()) >= capacity {
+                state = shared.cond.wait(state).expect("OT pool lock poisoned");
+            }
+            if state.stop {
+                return Ok(());
+            }
+        }
+        let choices = random_choices(batch);
+        let (sent, received) = if p.id == 0 {
+            let sent = sender.extend(p, batch)?;
+            (sent, receiver.extend(p, &choices)?)
+        } else {
+            let received = receiver.extend(p, &choices)?;
+            (sender.extend(p, batch)?, received)
+        };
+        let mut state = shared.state.lock().expect("OT pool lock poisoned");
+        state.sent.extend(sent.into_iter().map(sender_half));
+        state.received.extend(received.into_iter().zip(choices).map(|(k_c, c)| receiver_half(k_c, c)));
+        shared.cond.notify_all();
+    }
+}
@@ -0,0 +1,125 @@
+// One party's view of a two-party execution: its id, its channel to the
+// other party and its source of correlated randomness.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::beaver::TripleSource;
+use crate::channel::Channel;
+use crate::dealer::Dealer;
+use crate::error::Result;
+use crate::fastfield::{Group, Share};
+use crate::edabits::EdaBitSource;
+use crate::gates::GateKind;
+use crate::ot::{Extension, OtPool, OtSource};
+use crate::stats::Stats;
+
+pub struct Party {
+    /// 0 or 1.
+    pub id: usize,
+    pub chan: Box<dyn Channel>,
+    pub dealer: Box<dyn Dealer>,
+    /// Implementation of the interactive gates (`AND`, `OR` and `MUX`).
+    pub gate_kind: GateKind,
+    /// Where [`lt_const`](crate::comparison::lt_const) and its variants get
+    /// their edaBits from.
+    pub edabit_source: EdaBitSource,
+    /// Where [`mul`](crate::beaver::mul) gets its triples from.
+    pub triple_source: TripleSource,
+    /// Where the OT-based gates get their random OTs from.
+    pub ot_source: OtSource,
+    /// OT extension state, used with [`OtSource::Iknp`] and
+    /// [`TripleSource::Gilboa`].
+    pub ot_ext: Extension,
+    /// Pre-generated random OTs, used with [`OtSource::Pool`].
+    pub ot_pool: Option<OtPool>,
+    /// Communication sent by this party so far.
+    pub stats: Stats,
+}
+
+impl Party {
+    pub fn new(id: usize, chan: Box<dyn Channel>, dealer: Box<dyn Dealer>) -> Party {
+        assert!(id < 2, "party id must be 0 or 1");
+        Party {
+            id,
+            chan,
+            dealer,
+            gate_kind: GateKind::Ot,
+            edabit_source: EdaBitSource::Dealer,
+            triple_source: TripleSource::Dealer,
+            ot_source: OtSource::Dealer,
+            ot_ext: Extension::default(),
+            ot_pool: None,
+            stats: Stats::new(),
+        }
+    }
+
+    /// Id of the other party.
+    pub fn peer(&self) -> usize {
+        1 - self.id
+    }
+
+    /// Sends a message to the other party.
+    pub fn send<T: Serialize>(&mut self, msg: &T) -> Result<()> {
+        let msg = bincode::serialize(msg)?;
+        self.stats.on_send(msg.len());
+        self.chan.send_bytes(&msg)
+    }
+
+    /// Receives a message from the other party.
+    pub fn recv<T: DeserializeOwned>(&mut self) -> Result<T> {
+        let msg = self.chan.recv_bytes()?;
+        self.stats.on_recv();
+        Ok(bincode::deserialize(&msg)?)
+    }
+
+    /// Runs the protocol `f` and records its communication under `op` in
+    /// [`Party::stats`].
+    pub fn metered<T>(&mut self, op: &'static str, f: impl FnOnce(&mut Party) -> Result<T>) -> Result<T> {
+        let start = self.stats.begin();
+        let out = f(self);
+        self.stats.record(op, start);
+        out
+    }
+
+    /// Both parties send `msg` and receive the other party's message in the
+    /// same round.
+    pub fn exchange<T: Serialize + DeserializeOwned>(&mut self, msg: &T) -> Result<T> {
+        let msg = bincode::serialize(msg)?;
+        self.stats.on_exchange(msg.len());
+        self.chan.send_bytes(&msg)?;
+        let msg = self.chan.recv_bytes()?;
+        Ok(bincode::deserialize(&msg)?)
+    }
+
+    /// Opens an additively shared value to both parties.
+    pub fn open<T: Group + Serialize + DeserializeOwned>(&mut self, share: T) -> Result<T> {
+        let mut value = self.exchange(&share)?;
+        value.add(&share);
+        Ok(value)
+    }
+
+    /// Secret-shares `value`, a private input of this party: sends the other
+    /// party its share and returns this party's share.
+    pub fn share_input<T: Share + Serialize>(&mut self, value: &T) -> Result<T> {
+        let (mine, theirs) = value.share();
+        self.send(&theirs)?;
+        Ok(mine)
+    }
+
+    /// Receives this party's share of a private input of the other party,
+    /// shared with [`Party::share_input`].
+    pub fn recv_input<T: DeserializeOwned>(&mut self) -> Result<T> {
+        self.recv()
+    }
+
+    /// Shuts the channel to the other party down.
+    pub fn close(&mut self) -> Result<()> {
+        self.chan.close()
+    }
+
+    /// Opens a XOR-shared bit to both parties.
+    pub fn open_bit(&mut self, share: bool) -> Result<bool> {
+        Ok(self.exchange(&share)? ^ share)
+    }
+}
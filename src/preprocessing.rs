@@ -0,0 +1,204 @@
+// Offline phase: the dealer generates the correlated randomness of a whole
+// batch of comparisons up front and writes each party's half to its own
+// file. In the online phase every party loads its file as its `Dealer`.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::comparison::{lt_bits_gates, MASKED_MAX_K};
+use crate::dealer::{deal, Correlation, Dealer, Kind};
+use crate::error::{Error, Result};
+use crate::fastfield::N_BITS;
+use crate::gates::GateKind;
+
+const MAGIC: [u8; 8] = *b"RABBITPP";
+/// Version of the preprocessing file format.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    magic: [u8; 8],
+    version: u32,
+    party: usize,
+}
+
+/// Number of correlations of every kind needed by a batch of protocol calls.
+#[derive(Debug, Clone, Default)]
+pub struct Batch {
+    counts: HashMap<Kind, usize>,
+}
+
+impl Batch {
+    pub fn new() -> Batch {
+        Batch::default()
+    }
+
+    /// Adds `n` correlations of the given kind.
+    pub fn add(&mut self, kind: Kind, n: usize) {
+        *self.counts.entry(kind).or_default() += n;
+    }
+
+    /// Adds `n` interactive gates (`AND` or `OR`) of the given kind.
+    pub fn gates(&mut self, gate_kind: GateKind, n: usize) {
+        match gate_kind {
+            // one OT in each direction per gate
+            GateKind::Ot => {
+                self.add(Kind::Ot { sender: 0 }, n);
+                self.add(Kind::Ot { sender: 1 }, n);
+            }
+            GateKind::Triple => self.add(Kind::BoolTriple, n),
+        }
+    }
+
+    /// Adds `n` calls of [`lt_bits`](crate::comparison::lt_bits) on `k` bits.
+    pub fn lt_bits(&mut self, k: usize, gate_kind: GateKind, n: usize) {
+        self.gates(gate_kind, n * lt_bits_gates(k));
+    }
+
+    // LTConst: one edaBit and two LTBits on its bits.
+    fn lt_const_with(&mut self, edabit: Kind, k: usize, gate_kind: GateKind, n: usize) {
+        self.add(edabit, n);
+        self.lt_bits(k, gate_kind, 2 * n);
+    }
+
+    /// Adds `n` calls of [`lt_const`](crate::comparison::lt_const) on `k`
+    /// bits.
+    pub fn lt_const(&mut self, k: usize, gate_kind: GateKind, n: usize) {
+        if k > MASKED_MAX_K {
+            self.lt_const_field(gate_kind, n);
+        } else {
+            self.lt_const_with(Kind::EdaBit { bits: k }, k, gate_kind, n);
+        }
+    }
+
+    /// Adds `n` calls of
+    /// [`lt_const_field`](crate::comparison::lt_const_field).
+    pub fn lt_const_field(&mut self, gate_kind: GateKind, n: usize) {
+        self.lt_const_with(Kind::FieldEdaBit, N_BITS as usize, gate_kind, n);
+    }
+
+    /// Adds `n` calls of [`lt_const_ring`](crate::comparison::lt_const_ring)
+    /// on `k` bits.
+    pub fn lt_const_ring(&mut self, k: usize, gate_kind: GateKind, n: usize) {
+        self.lt_const_with(Kind::RingEdaBit { bits: k }, k, gate_kind, n);
+    }
+
+    /// Adds `n` calls of [`interval`](crate::comparison::interval) on `k`
+    /// bits.
+    pub fn interval(&mut self, k: usize, gate_kind: GateKind, n: usize) {
+        // one edaBit, and one LTBits per bound as in LTConst
+        if k > MASKED_MAX_K {
+            self.interval_field(gate_kind, n);
+        } else {
+            self.lt_const_with(Kind::EdaBit { bits: k }, k, gate_kind, n);
+        }
+    }
+
+    /// Adds `n` calls of
+    /// [`interval_field`](crate::comparison::interval_field).
+    pub fn interval_field(&mut self, gate_kind: GateKind, n: usize) {
+        self.lt_const_with(Kind::FieldEdaBit, N_BITS as usize, gate_kind, n);
+    }
+
+    /// Adds `n` calls of [`eq_bits`](crate::comparison::eq_bits) on `k` bits.
+    pub fn eq_bits(&mut self, k: usize, gate_kind: GateKind, n: usize) {
+        // the zero test ORs all k bits together
+        self.gates(gate_kind, n * (k - 1));
+    }
+
+    /// Adds `n` calls of [`eq_const`](crate::comparison::eq_const) or
+    /// [`eq_secret`](crate::comparison::eq_secret).
+    pub fn eq_const(&mut self, gate_kind: GateKind, n: usize) {
+        self.add(Kind::FieldEdaBit, n);
+        self.eq_bits(N_BITS as usize, gate_kind, n);
+    }
+
+    /// Adds `n` calls of [`a2b`](crate::a2b::a2b).
+    pub fn a2b(&mut self, gate_kind: GateKind, n: usize) {
+        const N: usize = N_BITS as usize;
+        self.add(Kind::FieldEdaBit, n);
+        // the adder on N bits, the reduction's adder on N + 1 bits and N MUXes
+        self.gates(gate_kind, n * (3 * N + 1));
+    }
+
+    /// Number of correlations of the given kind in the batch.
+    pub fn count(&self, kind: Kind) -> usize {
+        self.counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Offline phase: generates every correlation of the batch from `rng`,
+    /// returning the preprocessing of each party indexed by party id.
+    pub fn deal(&self, rng: &mut impl Rng) -> [Preprocessing; 2] {
+        let mut out = [Preprocessing::empty(0), Preprocessing::empty(1)];
+        for (&kind, &n) in &self.counts {
+            for _ in 0..n {
+                let [c_0, c_1] = deal(kind, rng);
+                out[0].queues.entry(kind).or_default().push_back(c_0);
+                out[1].queues.entry(kind).or_default().push_back(c_1);
+            }
+        }
+        out
+    }
+}
+
+/// One party's half of a batch of correlations, consumed in order by the
+/// online phase. Running out of a kind fails with [`Error::Exhausted`].
+#[derive(Debug, Clone)]
+pub struct Preprocessing {
+    party: usize,
+    queues: HashMap<Kind, VecDeque<Correlation>>,
+}
+
+impl Preprocessing {
+    fn empty(party: usize) -> Preprocessing {
+        Preprocessing { party, queues: HashMap::new() }
+    }
+
+    /// Id of the party this preprocessing belongs to.
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    /// Number of correlations of the given kind left.
+    pub fn remaining(&self, kind: Kind) -> usize {
+        self.queues.get(&kind).map_or(0, VecDeque::len)
+    }
+
+    /// Writes the preprocessing to `path`.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        let header = Header { magic: MAGIC, version: FORMAT_VERSION, party: self.party };
+        bincode::serialize_into(&mut w, &header)?;
+        bincode::serialize_into(&mut w, &self.queues)?;
+        w.flush()?;
+        Ok(())
+    }
+
+    /// Reads a preprocessing written by [`Preprocessing::write`].
+    pub fn read(path: impl AsRef<Path>) -> Result<Preprocessing> {
+        let mut r = BufReader::new(File::open(path)?);
+        let header: Header = bincode::deserialize_from(&mut r)
+            .map_err(|_| Error::Protocol("not a preprocessing file"))?;
+        if header.magic != MAGIC {
+            return Err(Error::Protocol("not a preprocessing file"));
+        }
+        if header.version != FORMAT_VERSION {
+            return Err(Error::Protocol("unsupported preprocessing file version"));
+        }
+        let queues = bincode::deserialize_from(&mut r)?;
+        Ok(Preprocessing { party: header.party, queues })
+    }
+}
+
+impl Dealer for Preprocessing {
+    fn next(&mut self, kind: Kind) -> Result<Correlation> {
+        self.queues
+            .get_mut(&kind)
+            .and_then(VecDeque::pop_front)
+            .ok_or(Error::Exhausted(kind))
+    }
+}
@@ -0,0 +1,136 @@
+// Signed integers on top of the unsigned comparisons.
+//
+// Over FE a signed value is its centred representative: v in
+// [-(p - 1) / 2, (p - 1) / 2] is stored as v mod p. On bit shares and over
+// Z_{2^K} it is K-bit two's complement. Either way, adding the offset
+// H = (p - 1) / 2, respectively 2^(K - 1), maps the signed range onto the
+// unsigned one monotonically, so every signed comparison is an unsigned one
+// on x + H against R + H. On bits the offset is a flip of the MSB.
+
+use bitvec::prelude::*;
+
+use crate::comparison::{compare_bits, compare_const, compare_const_field, compare_const_ring};
+use crate::comparison::{CmpOp, MAX_K, SECRET_CMP_MAX};
+use crate::error::Result;
+use crate::fastfield::{Group, FE, PRIME_ORDER};
+use crate::gates;
+use crate::party::Party;
+use crate::z2k::Z2k;
+
+/// Largest magnitude of a signed `FE` value, `(p - 1) / 2`.
+pub const FE_SIGNED_MAX: i64 = SECRET_CMP_MAX as i64;
+
+/// Encodes `v` in `[-FE_SIGNED_MAX, FE_SIGNED_MAX]` as its centred
+/// representative in `FE`.
+pub fn fe_from_signed(v: i64) -> FE {
+    assert!(v.unsigned_abs() <= FE_SIGNED_MAX as u64, "{} is out of the signed FE range", v);
+    if v < 0 { -FE::new(v.unsigned_abs()) } else { FE::new(v as u64) }
+}
+
+/// Decodes the centred representative of `x`.
+pub fn fe_to_signed(x: FE) -> i64 {
+    let v = x.value();
+    if v > SECRET_CMP_MAX { v as i64 - PRIME_ORDER as i64 } else { v as i64 }
+}
+
+/// Encodes `v` as `len`-bit two's complement, `[LSB, ..., MSB]`.
+pub fn bits_from_signed(v: i64, len: usize) -> BitVec<u8> {
+    assert!(len <= 64, "at most 64 bits");
+    gates::to_bits(v as u64, len)
+}
+
+/// Decodes `len`-bit two's complement bits, sign-extending the MSB.
+pub fn bits_to_signed(bits: &BitSlice<u8>) -> i64 {
+    let len = bits.len();
+    let v = bits.load_le::<u64>();
+    if len < 64 && bits[len - 1] { (v | (u64::MAX << len)) as i64 } else { v as i64 }
+}
+
+// Runs the unsigned comparison f on R + offset. When R + offset is negative,
+// R lies below the whole signed range, so the result is public and input by
+// P0: x > R and x >= R hold for every x.
+fn shifted(p: &mut Party, op: CmpOp, const_r: i64, offset: u64, f: impl FnOnce(&mut Party, u64) -> Result<u8>) -> Result<u8> {
+    let r = const_r as i128 + offset as i128;
+    if r < 0 {
+        return Ok((p.id == 0 && matches!(op, CmpOp::Gt | CmpOp::Ge)) as u8);
+    }
+    // beyond u64, R is above the whole range just as much as at u64::MAX
+    f(p, r.min(u64::MAX as i128) as u64)
+}
+
+/// Compares a XOR-shared `K`-bit two's complement vector `x` against any
+/// public signed constant `R`.
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_bits_signed<const K: usize>(p: &mut Party, op: CmpOp, const_r: i64, sh: &BitVec<u8>) -> Result<u8> {
+    // only P0 flips the MSB: x + 2^(K - 1) mod 2^K
+    let mut sh = sh.clone();
+    let msb = gates::not_gate(p, sh[K - 1]);
+    sh.set(K - 1, msb);
+    shifted(p, op, const_r, 1 << (K - 1), |p, r| compare_bits::<K>(p, op, r, &sh))
+}
+
+/// Compares an additively shared `FE` value `x`, the centred representative
+/// of a signed value in `[-2^(K-1), 2^(K-1))`, against any public signed
+/// constant `R`.
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const_signed<const K: usize>(p: &mut Party, op: CmpOp, const_r: i64, x: FE) -> Result<u8> {
+    assert!(K <= MAX_K, "LTConst supports at most {} bits", MAX_K);
+    let offset = 1 << (K - 1);
+    let mut x = x;
+    if p.id == 0 {
+        x.add(&FE::new(offset));
+    }
+    shifted(p, op, const_r, offset, |p, r| compare_const::<K>(p, op, r, x))
+}
+
+/// Compares an additively shared `FE` value `x`, the centred representative
+/// of a signed value, against any public signed constant `R`.
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const_field_signed(p: &mut Party, op: CmpOp, const_r: i64, x: FE) -> Result<u8> {
+    let mut x = x;
+    if p.id == 0 {
+        x.add(&FE::new(SECRET_CMP_MAX));
+    }
+    shifted(p, op, const_r, SECRET_CMP_MAX, |p, r| compare_const_field(p, op, r, x))
+}
+
+/// Compares an additively shared [`Z2k`] value `x`, in `K`-bit two's
+/// complement, against any public signed constant `R`.
+///
+/// Returns this party's XOR share of `[x op R]`.
+pub fn compare_const_ring_signed<const K: usize>(p: &mut Party, op: CmpOp, const_r: i64, x: Z2k<K>) -> Result<u8> {
+    let offset = 1 << (K - 1);
+    let mut x = x;
+    if p.id == 0 {
+        x.add(&Z2k::new(offset));
+    }
+    shifted(p, op, const_r, offset, |p, r| compare_const_ring::<K>(p, op, r, x))
+}
+
+/// Sign bit of a XOR-shared `K`-bit two's complement vector: its MSB, with
+/// no communication.
+///
+/// Returns this party's XOR share of `[x < 0]`.
+pub fn sign_bits<const K: usize>(sh: &BitVec<u8>) -> u8 {
+    sh[K - 1] as u8
+}
+
+/// Sign bit of an additively shared `FE` value `x`, the centred
+/// representative of a signed value.
+///
+/// Returns this party's XOR share of `[x < 0]`.
+pub fn sign_field(p: &mut Party, x: FE) -> Result<u8> {
+    p.metered("sign_field", |p| compare_const_field_signed(p, CmpOp::Lt, 0, x))
+}
+
+/// Signed secret comparison of two additively shared `FE` values `x` and
+/// `y`, both centred representatives in `[-FE_SIGNED_MAX / 2,
+/// FE_SIGNED_MAX / 2]`, so that `x - y` does not wrap around.
+///
+/// Returns this party's XOR share of `[x < y]`.
+pub fn lt_secret_signed(p: &mut Party, x: FE, y: FE) -> Result<u8> {
+    p.metered("lt_secret_signed", |p| sign_field(p, x - y))
+}
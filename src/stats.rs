@@ -0,0 +1,245 @@
+// Communication accounting of one party: bytes, messages and rounds, in
+// total and per protocol.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Sub};
+
+/// Communication sent by one party.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cost {
+    /// Encoded message bytes, not counting the transport's framing.
+    pub bytes: u64,
+    pub messages: u64,
+    /// Latency in rounds: the sequential flights of messages between the
+    /// two parties, in either direction. A flight ends whenever the
+    /// direction changes, and a simultaneous exchange is one flight of its
+    /// own. Unlike bytes and messages, this is the same for both parties.
+    pub rounds: u64,
+}
+
+impl Add for Cost {
+    type Output = Cost;
+
+    fn add(self, other: Cost) -> Cost {
+        Cost {
+            bytes: self.bytes + other.bytes,
+            messages: self.messages + other.messages,
+            rounds: self.rounds + other.rounds,
+        }
+    }
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Cost) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Cost {
+    type Output = Cost;
+
+    fn sub(self, other: Cost) -> Cost {
+        Cost {
+            bytes: self.bytes - other.bytes,
+            messages: self.messages - other.messages,
+            rounds: self.rounds - other.rounds,
+        }
+    }
+}
+
+impl Display for Cost {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} bytes, {} messages, {} rounds", self.bytes, self.messages, self.rounds)
+    }
+}
+
+/// Calls of one protocol and the communication they sent, including that
+/// of the sub-protocols they call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpStats {
+    pub calls: u64,
+    pub cost: Cost,
+}
+
+impl Display for OpStats {
+    // Average cost of one call.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let calls = self.calls.max(1) as f64;
+        write!(
+            f,
+            "{} calls, per call: {:.1} bytes, {:.1} messages, {:.1} rounds",
+            self.calls,
+            self.cost.bytes as f64 / calls,
+            self.cost.messages as f64 / calls,
+            self.cost.rounds as f64 / calls,
+        )
+    }
+}
+
+/// Communication of one party, in total and per protocol.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub total: Cost,
+    pub ops: BTreeMap<&'static str, OpStats>,
+    // Direction of the current flight, None before the first message.
+    flight: Option<Flight>,
+    // One entry per op in progress, innermost last: None until the op's
+    // first message, then whether that message continued a flight started
+    // before the op.
+    open: Vec<Option<bool>>,
+}
+
+// Direction of a flight of messages, as seen by this party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flight {
+    Send,
+    Recv,
+    // Both parties send, then receive: a simultaneous exchange.
+    Exchange,
+}
+
+impl Stats {
+    pub fn new() -> Stats {
+        Stats::default()
+    }
+
+    /// Records one sent message of `len` bytes.
+    pub fn on_send(&mut self, len: usize) {
+        self.on_message(Flight::Send);
+        self.total.bytes += len as u64;
+        self.total.messages += 1;
+    }
+
+    /// Records one received message.
+    pub fn on_recv(&mut self) {
+        self.on_message(Flight::Recv);
+    }
+
+    /// Records a simultaneous exchange: one sent message of `len` bytes, and
+    /// the other party's message received in the same flight.
+    pub fn on_exchange(&mut self, len: usize) {
+        self.on_message(Flight::Exchange);
+        self.total.bytes += len as u64;
+        self.total.messages += 1;
+    }
+
+    // Starts a new flight unless the message continues the current one: a
+    // send after a send, or a receive after a receive. An exchange always
+    // waits for what came before it, and anything after it waits for it.
+    fn on_message(&mut self, flight: Flight) {
+        let continued = flight != Flight::Exchange && self.flight == Some(flight);
+        if !continued {
+            self.total.rounds += 1;
+            self.flight = Some(flight);
+        }
+        // the ops that have not seen a message yet are the innermost ones
+        for first in self.open.iter_mut().rev() {
+            if first.is_some() {
+                break;
+            }
+            *first = Some(continued);
+        }
+    }
+
+    /// Starts a call of an op, to be ended by [`Stats::record`]. Returns the
+    /// total at its start.
+    pub fn begin(&mut self) -> Cost {
+        self.open.push(None);
+        self.total
+    }
+
+    /// Records one call of `op`, begun by [`Stats::begin`] when the total was
+    /// `start`.
+    ///
+    /// A call whose first message continues the flight of whatever came
+    /// before it still takes that round: it is counted in the op's rounds,
+    /// though not twice in the total.
+    pub fn record(&mut self, op: &'static str, start: Cost) {
+        let mut cost = self.total - start;
+        if let Some(Some(true)) = self.open.pop() {
+            cost.rounds += 1;
+        }
+        let entry = self.ops.entry(op).or_default();
+        entry.calls += 1;
+        entry.cost += cost;
+    }
+
+    /// Returns the stats of `op`, zero if it was never called.
+    pub fn op(&self, op: &str) -> OpStats {
+        self.ops.get(op).copied().unwrap_or_default()
+    }
+
+    /// Adds the counts of `other`, e.g. of another run, to these.
+    pub fn merge(&mut self, other: &Stats) {
+        self.total += other.total;
+        for (op, s) in &other.ops {
+            let entry = self.ops.entry(op).or_default();
+            entry.calls += s.calls;
+            entry.cost += s.cost;
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "total: {}", self.total)?;
+        for (op, s) in &self.ops {
+            writeln!(f, "  {:<20} {}", op, s)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cost;
+    use crate::channel::run_in_memory;
+    use crate::error::Result;
+    use crate::gates::{self, OtInput};
+    use crate::party::Party;
+
+    // Runs `f` as one metered op for both parties, returning each one's cost.
+    fn cost_of(f: impl Fn(&mut Party) -> Result<()> + Sync) -> [Cost; 2] {
+        let (c_0, c_1) = run_in_memory(|p| {
+            p.metered("op", &f).expect("protocol failed");
+            p.stats.op("op").cost
+        });
+        [c_0, c_1]
+    }
+
+    fn cost(bytes: u64, messages: u64, rounds: u64) -> Cost {
+        Cost { bytes, messages, rounds }
+    }
+
+    #[test]
+    fn exchange() {
+        let op = cost_of(|p| p.exchange(&true).map(drop));
+        assert_eq!(op, [cost(1, 1, 1); 2]);
+    }
+
+    #[test]
+    fn one_out_of_two_ot() {
+        let op = cost_of(|p| {
+            let input = if p.id == 0 { OtInput::Sender([0, 1]) } else { OtInput::Receiver(1) };
+            gates::one_out_of_two_ot(p, input).map(drop)
+        });
+        // z from the receiver, then y from the sender
+        assert_eq!(op, [cost(2, 1, 2), cost(1, 1, 2)]);
+    }
+
+    #[test]
+    fn and_gate_triple() {
+        let op = cost_of(|p| gates::and_gate_triple(p, true, false).map(drop));
+        // d and e in one exchange
+        assert_eq!(op, [cost(2, 1, 1); 2]);
+    }
+
+    #[test]
+    fn and_gate_ot() {
+        let op = cost_of(|p| gates::and_gate_ot(p, true, false).map(drop));
+        // z, then y with z', then y'
+        assert_eq!(op.map(|c| c.rounds), [3, 3]);
+        assert_eq!(op.map(|c| c.messages), [2, 2]);
+    }
+}
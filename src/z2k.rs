@@ -0,0 +1,93 @@
+// Implements the ring Z_{2^K} of integers modulo a power of two, for
+// K in 1..=64.
+//
+// Values are kept in a u64 and reduced by masking off the bits above K, so
+// all arithmetic is plain wrapping u64 arithmetic followed by a mask.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+use crate::fastfield::{FromRng, Group, Share};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Z2k<const K: usize> {
+    // Always reduced: in range 0..2^K.
+    val: u64,
+}
+
+impl<const K: usize> Z2k<K> {
+    // Mask to mask off all bits that aren't used in the ring elements.
+    const MASK: u64 = if K >= 64 { u64::MAX } else { (1 << K) - 1 };
+
+    // Construct a new Z2k value.  Accepts any u64, and creates an element
+    // that represents that value modulo 2^K.
+    pub fn new(v: u64) -> Self {
+        assert!(K > 0 && K <= 64, "Z2k supports 1 to 64 bits");
+        Z2k { val: v & Self::MASK }
+    }
+
+    // Return the value of this element, as an integer in range 0..2^K.
+    pub fn value(self) -> u64 {
+        self.val
+    }
+}
+
+impl<const K: usize> Group for Z2k<K> {
+    #[inline]
+    fn zero() -> Self {
+        Z2k::new(0)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Z2k::new(1)
+    }
+
+    #[inline]
+    fn negate(&mut self) {
+        *self = Z2k::new(self.val.wrapping_neg());
+    }
+
+    #[inline]
+    fn reduce(&mut self) {}
+
+    #[inline]
+    fn add(&mut self, other: &Self) {
+        *self = Z2k::new(self.val.wrapping_add(other.val));
+    }
+
+    #[inline]
+    fn add_lazy(&mut self, other: &Self) {
+        self.add(other);
+    }
+
+    #[inline]
+    fn mul(&mut self, other: &Self) {
+        *self = Z2k::new(self.val.wrapping_mul(other.val));
+    }
+
+    #[inline]
+    fn mul_lazy(&mut self, other: &Self) {
+        self.mul(other);
+    }
+
+    #[inline]
+    fn sub(&mut self, other: &Self) {
+        *self = Z2k::new(self.val.wrapping_sub(other.val));
+    }
+}
+
+impl<const K: usize> FromRng for Z2k<K> {
+    fn from_rng(&mut self, rng: &mut impl rand::Rng) {
+        // Masking a uniform u64 is already uniform modulo 2^K.
+        *self = Z2k::new(rng.next_u64());
+    }
+}
+
+impl<const K: usize> Share for Z2k<K> {}
+
+impl<const K: usize> Display for Z2k<K> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.val, f)
+    }
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use bitvec::field::BitField;
+use bitvec::vec::BitVec;
+
+use rabbit_mpc_comparisons::edabits::EdaBitSource;
+use rabbit_mpc_comparisons::fastfield::N_BITS;
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+use rabbit_mpc_comparisons::{a2b, run_with_dealers, Batch, Result, Share, FE};
+
+use common::{run, share_pair, ITER};
+
+fn check(name: &str, x: &[FE], bits_0: &[BitVec<u8>], bits_1: &[BitVec<u8>]) {
+    for ((x, b_0), b_1) in x.iter().zip(bits_0).zip(bits_1) {
+        assert_eq!(b_0.len(), N_BITS as usize);
+        assert_eq!(gates::reconstruct_shares(b_0, b_1).load_le::<u64>(), x.value(), "A2B ({}): {}", name, x);
+    }
+}
+
+// Bit decomposition of FE shares with both edaBit sources.
+#[test]
+fn a2b_with_both_sources() {
+    let x: Vec<FE> = (0..ITER).map(|_| FE::random()).collect();
+    let x_sh: Vec<[FE; 2]> = x.iter().map(|&x| share_pair(x)).collect();
+    for source in [EdaBitSource::Dealer, EdaBitSource::TwoParty] {
+        let (bits_0, bits_1) = run(GateKind::Ot, |p| {
+            p.edabit_source = source;
+            x_sh.iter().map(|x_sh| a2b(p, x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        check(&format!("{:?}", source), &x, &bits_0, &bits_1);
+    }
+}
+
+// Bit decomposition with the preprocessed correlations counted by
+// `Batch::a2b`, which must be used up exactly.
+#[test]
+fn a2b_preprocessed() {
+    let x: Vec<FE> = (0..ITER).map(|_| FE::random()).collect();
+    let x_sh: Vec<[FE; 2]> = x.iter().map(|&x| share_pair(x)).collect();
+    let mut batch = Batch::new();
+    batch.a2b(GateKind::Ot, ITER);
+    let [pre_0, pre_1] = batch.deal(&mut rand::thread_rng());
+    let (out_0, out_1) = run_with_dealers([Box::new(pre_0), Box::new(pre_1)], |p| {
+        let bits = x_sh.iter().map(|x_sh| a2b(p, x_sh[p.id])).collect::<Result<Vec<_>>>();
+        (bits, a2b(p, x_sh[0][p.id]).is_err())
+    });
+    assert!(out_0.1 && out_1.1, "A2B (preprocessed): the batch is too large");
+    check("preprocessed", &x, &out_0.0.expect("batch too small"), &out_1.0.expect("batch too small"));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use rand::Rng;
+
+use rabbit_mpc_comparisons::edabits::EdaBitSource;
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{b2a, b2a_bits, lt_const, Group, Result, FE};
+
+use common::{run, share_bits, share_pair, ITER, K};
+
+// Converts XOR-shared bits, and LTConst results, into FE shares with both
+// sources.
+#[test]
+fn b2a_with_both_sources() {
+    let mut rng = rand::thread_rng();
+    let x: u64 = rng.gen_range(0..(1 << K));
+    let x_sh = share_bits(x, K);
+    let inputs: Vec<(u64, [FE; 2])> = (0..ITER)
+        .map(|_| (rng.gen_range(0..(1 << K)), share_pair(FE::new(rng.gen_range(0..(1 << K))))))
+        .collect();
+    for source in [EdaBitSource::Dealer, EdaBitSource::TwoParty] {
+        let ((v_0, w_0), (v_1, w_1)) = run(GateKind::Ot, |p| {
+            p.edabit_source = source;
+            let v = b2a_bits(p, &x_sh[p.id])?;
+            let w = inputs
+                .iter()
+                .map(|(r, x_sh)| {
+                    let c = lt_const::<K>(p, *r, x_sh[p.id])?;
+                    b2a(p, c)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok((v, w))
+        });
+        for (j, (mut v, v_1)) in v_0.into_iter().zip(&v_1).enumerate() {
+            v.add(v_1);
+            assert_eq!(v, FE::new((x >> j) & 1), "B2A ({:?}): bit {} of {}", source, j, x);
+        }
+        for (((r, x_sh), mut w), w_1) in inputs.iter().zip(w_0).zip(&w_1) {
+            let x = (x_sh[0] + x_sh[1]).value();
+            w.add(w_1);
+            assert_eq!(w, FE::new((x <= *r) as u64), "B2A ({:?}) of LT Const: {} <= {}", source, x, r);
+        }
+    }
+}
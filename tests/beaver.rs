@@ -0,0 +1,32 @@
+mod common;
+
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{gen_triples, mul, Result, Share, TripleSource, FE};
+
+use common::{run, share_pair, ITER};
+
+// Triples generated with Gilboa's protocol.
+#[test]
+fn gilboa_triples() {
+    let (t_0, t_1) = run(GateKind::Ot, |p| gen_triples(p, ITER));
+    assert_eq!(t_0.len(), ITER);
+    for (t_0, t_1) in t_0.iter().zip(&t_1) {
+        assert_eq!(t_0.c + t_1.c, (t_0.a + t_1.a) * (t_0.b + t_1.b), "Gilboa: c != a * b");
+    }
+}
+
+// Multiplication with triples from the dealer and from Gilboa's protocol.
+#[test]
+fn mul_with_both_sources() {
+    let inputs: Vec<([FE; 2], [FE; 2])> =
+        (0..ITER).map(|_| (share_pair(FE::random()), share_pair(FE::random()))).collect();
+    for source in [TripleSource::Dealer, TripleSource::Gilboa] {
+        let (z_0, z_1) = run(GateKind::Ot, |p| {
+            p.triple_source = source;
+            inputs.iter().map(|(x_sh, y_sh)| mul(p, x_sh[p.id], y_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (((x_sh, y_sh), z_0), z_1) in inputs.iter().zip(z_0).zip(z_1) {
+            assert_eq!(z_0 + z_1, (x_sh[0] + x_sh[1]) * (y_sh[0] + y_sh[1]), "MUL ({:?} triples)", source);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Helpers shared by the integration tests: running both parties in memory
+// and sharing their inputs.
+
+#![allow(dead_code)]
+
+use bitvec::vec::BitVec;
+
+use rabbit_mpc_comparisons::edabits::EdaBitSource;
+use rabbit_mpc_comparisons::fastfield::N_BITS;
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+use rabbit_mpc_comparisons::ot::OtSource;
+use rabbit_mpc_comparisons::{run_in_memory, Party, Result, Share};
+
+// Random inputs per test, fewer than the driver's 100 iterations so
+// that the tests stay quick in debug builds
+pub const ITER: usize = 20;
+// Bit length of the compared values
+pub const K: usize = 8;
+// Bit length of the ring Z_{2^RING_K}, matching the field's edaBits
+pub const RING_K: usize = N_BITS as usize;
+
+// Runs `f` for both parties with the given gate implementation.
+pub fn run<R, F>(kind: GateKind, f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> Result<R> + Sync,
+{
+    let (out_0, out_1) = run_in_memory(|p| {
+        p.gate_kind = kind;
+        f(p)
+    });
+    (out_0.expect("party 0 failed"), out_1.expect("party 1 failed"))
+}
+
+// Runs `f` for both parties with OT-based gates fed from `source`.
+pub fn run_ot<R, F>(source: OtSource, f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> Result<R> + Sync,
+{
+    run(GateKind::Ot, |p| {
+        p.ot_source = source;
+        f(p)
+    })
+}
+
+// Runs `f` for both parties with edaBits generated by the two parties.
+pub fn run_two_party<R, F>(f: F) -> (R, R)
+where
+    R: Send,
+    F: Fn(&mut Party) -> Result<R> + Sync,
+{
+    run(GateKind::Ot, |p| {
+        p.edabit_source = EdaBitSource::TwoParty;
+        f(p)
+    })
+}
+
+// Additive shares of `x`, indexed by party.
+pub fn share_pair<T: Share>(x: T) -> [T; 2] {
+    let (x_0, x_1) = x.share();
+    [x_0, x_1]
+}
+
+// XOR shares of the `len` low bits of `x`, indexed by party.
+pub fn share_bits(x: u64, len: usize) -> [BitVec<u8>; 2] {
+    let (x_0, x_1) = gates::secret_share(&gates::to_bits(x, len));
+    [x_0, x_1]
+}
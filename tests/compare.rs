@@ -0,0 +1,60 @@
+mod common;
+
+use bitvec::vec::BitVec;
+
+use rabbit_mpc_comparisons::fastfield::PRIME_ORDER;
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{compare_bits, compare_const, compare_const_field, compare_const_ring, CmpOp, Result, FE, Z2k};
+
+use common::{run, share_bits, share_pair};
+
+const CMP_K: usize = 4;
+
+// The four operators exhaustively on a small domain: every x in
+// [0, 2^CMP_K) against every R in [0, 2^CMP_K] and u64::MAX, on bits, over
+// FE and over the ring.
+#[test]
+fn compare_exhaustive() {
+    let m = 1u64 << CMP_K;
+    let cases: Vec<(CmpOp, u64, u64)> = CmpOp::ALL
+        .iter()
+        .flat_map(|&op| (0..=m).chain([u64::MAX]).flat_map(move |r| (0..m).map(move |x| (op, r, x))))
+        .collect();
+    let bits_sh: Vec<[BitVec<u8>; 2]> = (0..m).map(|x| share_bits(x, CMP_K)).collect();
+    let fe_sh: Vec<[FE; 2]> = (0..m).map(|x| share_pair(FE::new(x))).collect();
+    let ring_sh: Vec<[Z2k<CMP_K>; 2]> = (0..m).map(|x| share_pair(Z2k::<CMP_K>::new(x))).collect();
+    let ((w_bits, w_fe, w_ring), (v_bits, v_fe, v_ring)) = run(GateKind::Ot, |p| {
+        let mut w = (Vec::new(), Vec::new(), Vec::new());
+        for &(op, r, x) in &cases {
+            let x = x as usize;
+            w.0.push(compare_bits::<CMP_K>(p, op, r, &bits_sh[x][p.id])?);
+            w.1.push(compare_const::<CMP_K>(p, op, r, fe_sh[x][p.id])?);
+            w.2.push(compare_const_ring::<CMP_K>(p, op, r, ring_sh[x][p.id])?);
+        }
+        Ok(w)
+    });
+    for (i, &(op, r, x)) in cases.iter().enumerate() {
+        let expected = op.eval(x, r);
+        assert_eq!(w_bits[i] ^ v_bits[i] != 0, expected, "Compare (bits): {} {:?} {}", x, op, r);
+        assert_eq!(w_fe[i] ^ v_fe[i] != 0, expected, "Compare (FE): {} {:?} {}", x, op, r);
+        assert_eq!(w_ring[i] ^ v_ring[i] != 0, expected, "Compare (ring): {} {:?} {}", x, op, r);
+    }
+}
+
+// The four operators over the whole field, at both of its ends.
+#[test]
+fn compare_field_ends() {
+    let p = PRIME_ORDER;
+    let ends = [0, 1, 2, p / 2, p - 2, p - 1];
+    let cases: Vec<(CmpOp, u64, u64)> = CmpOp::ALL
+        .iter()
+        .flat_map(|&op| ends.into_iter().chain([p, u64::MAX]).flat_map(move |r| ends.map(move |x| (op, r, x))))
+        .collect();
+    let x_sh: Vec<[FE; 2]> = cases.iter().map(|&(_, _, x)| share_pair(FE::new(x))).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        cases.iter().zip(&x_sh).map(|(&(op, r, _), x_sh)| compare_const_field(p, op, r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for ((&(op, r, x), w_0), w_1) in cases.iter().zip(w_0).zip(w_1) {
+        assert_eq!(w_0 ^ w_1 != 0, op.eval(x, r), "Compare (field): {} {:?} {}", x, op, r);
+    }
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use rand::Rng;
+
+use rabbit_mpc_comparisons::comparison::{MAX_K, SECRET_CMP_MAX};
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{lt_bits, lt_bits_secret, lt_const, lt_const_field, lt_const_ring, lt_secret, lt_secret_fe};
+use rabbit_mpc_comparisons::{Result, Share, FE, Z2k};
+
+use common::{run, share_bits, share_pair, ITER, K, RING_K};
+
+// LTBits against a public R, with both gate implementations.
+#[test]
+fn lt_bits_random() {
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<(u64, u64)> = (0..ITER).map(|_| (rng.gen_range(0..(1 << K)), rng.gen_range(0..(1 << K)))).collect();
+    let x_sh: Vec<_> = inputs.iter().map(|&(_, x)| share_bits(x, K)).collect();
+    for kind in [GateKind::Ot, GateKind::Triple] {
+        let (w_0, w_1) = run(kind, |p| {
+            inputs.iter().zip(&x_sh).map(|(&(r, _), x_sh)| lt_bits::<K>(p, r, &x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (i, &(r, x)) in inputs.iter().enumerate() {
+            assert_eq!(w_0[i] ^ w_1[i] != 0, r <= x, "LT Bits ({:?}): {} <= {}", kind, r, x);
+        }
+    }
+}
+
+// LTBits of two XOR-shared bit vectors, with both gate implementations.
+#[test]
+fn lt_bits_secret_random() {
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<(u64, u64)> = (0..ITER).map(|_| (rng.gen_range(0..(1 << K)), rng.gen_range(0..(1 << K)))).collect();
+    let shares: Vec<_> = inputs.iter().map(|&(y, x)| (share_bits(y, K), share_bits(x, K))).collect();
+    for kind in [GateKind::Ot, GateKind::Triple] {
+        let (w_0, w_1) = run(kind, |p| {
+            shares.iter().map(|(y_sh, x_sh)| lt_bits_secret::<K>(p, &y_sh[p.id], &x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (i, &(y, x)) in inputs.iter().enumerate() {
+            assert_eq!(w_0[i] ^ w_1[i] != 0, y <= x, "LT Bits (secret, {:?}): {} <= {}", kind, y, x);
+        }
+    }
+}
+
+// LTConst on K-bit values embedded in FE.
+#[test]
+fn lt_const_random() {
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<(u64, u64)> = (0..ITER).map(|_| (rng.gen_range(0..(1 << K)), rng.gen_range(0..(1 << K)))).collect();
+    let x_sh: Vec<[FE; 2]> = inputs.iter().map(|&(_, x)| share_pair(FE::new(x))).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        inputs.iter().zip(&x_sh).map(|(&(r, _), x_sh)| lt_const::<K>(p, r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (i, &(r, x)) in inputs.iter().enumerate() {
+        assert_eq!(w_0[i] ^ w_1[i] != 0, x <= r, "LT Const: {} <= {}", x, r);
+    }
+}
+
+// LTConst over the whole field.
+#[test]
+fn lt_const_field_random() {
+    let inputs: Vec<(u64, FE)> = (0..ITER).map(|_| (FE::random().value(), FE::random())).collect();
+    let x_sh: Vec<[FE; 2]> = inputs.iter().map(|&(_, x)| share_pair(x)).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        inputs.iter().zip(&x_sh).map(|(&(r, _), x_sh)| lt_const_field(p, r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (i, &(r, x)) in inputs.iter().enumerate() {
+        assert_eq!(w_0[i] ^ w_1[i] != 0, x.value() <= r, "LT Const (field): {} <= {}", x, r);
+    }
+}
+
+// LTConst over the ring Z_{2^RING_K}.
+#[test]
+fn lt_const_ring_random() {
+    let inputs: Vec<(u64, Z2k<RING_K>)> = (0..ITER).map(|_| (Z2k::<RING_K>::random().value(), Z2k::random())).collect();
+    let x_sh: Vec<[Z2k<RING_K>; 2]> = inputs.iter().map(|&(_, x)| share_pair(x)).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        inputs.iter().zip(&x_sh).map(|(&(r, _), x_sh)| lt_const_ring(p, r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (i, &(r, x)) in inputs.iter().enumerate() {
+        assert_eq!(w_0[i] ^ w_1[i] != 0, x.value() <= r, "LT Const (ring): {} <= {}", x.value(), r);
+    }
+}
+
+// The secret comparison of two shared values up to SECRET_CMP_MAX, as a
+// XOR-shared bit and as an FE share.
+#[test]
+fn lt_secret_random() {
+    let mut rng = rand::thread_rng();
+    let mut pairs: Vec<(u64, u64)> =
+        (0..ITER).map(|_| (rng.gen_range(0..=SECRET_CMP_MAX), rng.gen_range(0..=SECRET_CMP_MAX))).collect();
+    pairs.extend([(0, SECRET_CMP_MAX), (SECRET_CMP_MAX, 0), (7, 7)]);
+    let pairs_sh: Vec<([FE; 2], [FE; 2])> = pairs.iter().map(|&(x, y)| (share_pair(FE::new(x)), share_pair(FE::new(y)))).collect();
+    let ((c_0, d_0), (c_1, d_1)) = run(GateKind::Ot, |p| {
+        let mut c = (Vec::new(), Vec::new());
+        for (x_sh, y_sh) in &pairs_sh {
+            c.0.push(lt_secret(p, x_sh[p.id], y_sh[p.id])?);
+            c.1.push(lt_secret_fe(p, x_sh[p.id], y_sh[p.id])?);
+        }
+        Ok(c)
+    });
+    for (i, &(x, y)) in pairs.iter().enumerate() {
+        assert_eq!(c_0[i] ^ c_1[i] != 0, x < y, "LT Secret: {} < {}", x, y);
+        assert_eq!((d_0[i] + d_1[i]).value(), (x < y) as u64, "LT Secret (FE): {} < {}", x, y);
+    }
+}
+
+// LTBits and LTConst at width W, on random inputs and at both ends of the
+// domain.
+fn check_width<const W: usize>() {
+    let mut rng = rand::thread_rng();
+    let top = (1u64 << W) - 1;
+    let mut inputs: Vec<(u64, u64)> = (0..ITER).map(|_| (rng.gen_range(0..=top), rng.gen_range(0..=top))).collect();
+    inputs.extend([(0, 0), (0, top), (top, 0), (top, top), (top - 1, top)]);
+    let bits_sh: Vec<_> = inputs.iter().map(|&(_, x)| share_bits(x, W)).collect();
+    let fe_sh: Vec<[FE; 2]> = inputs.iter().map(|&(_, x)| share_pair(FE::new(x))).collect();
+    let ((a_0, b_0), (a_1, b_1)) = run(GateKind::Ot, |p| {
+        let mut w = (Vec::new(), Vec::new());
+        for (i, &(r, _)) in inputs.iter().enumerate() {
+            w.0.push(lt_bits::<W>(p, r, &bits_sh[i][p.id])?);
+            w.1.push(lt_const::<W>(p, r, fe_sh[i][p.id])?);
+        }
+        Ok(w)
+    });
+    for (i, &(r, x)) in inputs.iter().enumerate() {
+        assert_eq!(a_0[i] ^ a_1[i] != 0, r <= x, "LT Bits ({} bits): {} <= {}", W, r, x);
+        assert_eq!(b_0[i] ^ b_1[i] != 0, x <= r, "LT Const ({} bits): {} <= {}", W, x, r);
+    }
+}
+
+#[test]
+fn width_16() {
+    check_width::<16>();
+}
+
+#[test]
+fn width_32() {
+    check_width::<32>();
+}
+
+#[test]
+fn width_40() {
+    check_width::<40>();
+}
+
+#[test]
+fn width_max() {
+    check_width::<MAX_K>();
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use bitvec::field::BitField;
+use rand::Rng;
+
+use rabbit_mpc_comparisons::edabits;
+use rabbit_mpc_comparisons::gates;
+use rabbit_mpc_comparisons::{lt_const, lt_const_field, lt_const_ring, Group, Share, FE, Z2k};
+
+use common::{run_two_party, share_pair, K, RING_K};
+
+const EDA_ITER: usize = 10;
+
+// The dealer-free edaBits: the arithmetic and the binary shares must hold
+// the same r.
+#[test]
+fn two_party_edabits() {
+    for _ in 0..EDA_ITER {
+        let ((r, f, g), (r_1, f_1, g_1)) = run_two_party(|p| {
+            Ok((
+                edabits::gen_edabit::<K>(p)?,
+                edabits::gen_field_edabit(p)?,
+                edabits::gen_ring_edabit::<RING_K>(p)?,
+            ))
+        });
+        let r_bits = gates::reconstruct_shares(&r.1, &r_1.1);
+        assert_eq!(r.0 + r_1.0, FE::new(r_bits.load_le::<u64>()), "edaBit");
+        assert!((r.0 + r_1.0).value() < 1 << K, "edaBit out of range");
+        let f_bits = gates::reconstruct_shares(&f.1, &f_1.1);
+        assert_eq!((f.0 + f_1.0).value(), f_bits.load_le::<u64>(), "field edaBit");
+        let g_bits = gates::reconstruct_shares(&g.1, &g_1.1);
+        let mut g_r = g.0;
+        g_r.add(&g_1.0);
+        assert_eq!(g_r.value(), g_bits.load_le::<u64>(), "ring edaBit");
+    }
+}
+
+// The comparisons must work on top of the dealer-free edaBits.
+#[test]
+fn lt_const_with_two_party_edabits() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..EDA_ITER {
+        let const_r: u64 = rng.gen_range(0..(1 << K));
+        let x: u64 = rng.gen_range(0..(1 << K));
+        let x_sh = share_pair(FE::new(x));
+        let (w_0, w_1) = run_two_party(|p| lt_const::<K>(p, const_r, x_sh[p.id]));
+        assert_eq!(w_0 ^ w_1 != 0, x <= const_r, "LT Const (two-party edaBits): {} <= {}", x, const_r);
+
+        let const_r = FE::random().value();
+        let x = FE::random();
+        let x_sh = share_pair(x);
+        let (w_0, w_1) = run_two_party(|p| lt_const_field(p, const_r, x_sh[p.id]));
+        assert_eq!(w_0 ^ w_1 != 0, x.value() <= const_r, "LT Const (field, two-party edaBits)");
+
+        let x = Z2k::<RING_K>::random();
+        let x_sh = share_pair(x);
+        let (w_0, w_1) = run_two_party(|p| lt_const_ring(p, const_r, x_sh[p.id]));
+        assert_eq!(w_0 ^ w_1 != 0, x.value() <= const_r, "LT Const (ring, two-party edaBits)");
+    }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use rand::Rng;
+
+use rabbit_mpc_comparisons::edabits::EdaBitSource;
+use rabbit_mpc_comparisons::fastfield::PRIME_ORDER;
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{eq_bits, eq_const, eq_secret, run_with_dealers, Batch, Group, Result, Share, FE};
+
+use common::{run, share_bits, share_pair, ITER, K};
+
+// Half of the pairs equal, and both ends of the field.
+fn pairs() -> Vec<(u64, FE)> {
+    let mut pairs: Vec<(u64, FE)> = (0..ITER)
+        .map(|i| {
+            let x = FE::random();
+            (if i % 2 == 0 { x.value() } else { FE::random().value() }, x)
+        })
+        .collect();
+    pairs.extend([(0, FE::zero()), (PRIME_ORDER - 1, -FE::one()), (0, -FE::one()), (PRIME_ORDER - 1, FE::zero())]);
+    pairs
+}
+
+// EQBits over the whole K-bit domain.
+#[test]
+fn eq_bits_exhaustive() {
+    let const_r: u64 = rand::thread_rng().gen_range(0..(1 << K));
+    let x_sh: Vec<_> = (0..1u64 << K).map(|x| share_bits(x, K)).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        x_sh.iter().map(|x_sh| eq_bits::<K>(p, const_r, &x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (x, (w_0, w_1)) in w_0.iter().zip(&w_1).enumerate() {
+        assert_eq!(w_0 ^ w_1 != 0, x as u64 == const_r, "EQ Bits: {} == {}", x, const_r);
+    }
+}
+
+// EQConst with both edaBit sources.
+#[test]
+fn eq_const_with_both_sources() {
+    let pairs = pairs();
+    let pairs_sh: Vec<(u64, [FE; 2])> = pairs.iter().map(|&(r, x)| (r, share_pair(x))).collect();
+    for source in [EdaBitSource::Dealer, EdaBitSource::TwoParty] {
+        let (w_0, w_1) = run(GateKind::Ot, |p| {
+            p.edabit_source = source;
+            pairs_sh.iter().map(|(r, x_sh)| eq_const(p, *r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (((r, x), w_0), w_1) in pairs.iter().zip(w_0).zip(w_1) {
+            assert_eq!(w_0 ^ w_1 != 0, x.value() == *r, "EQ Const ({:?}): {} == {}", source, x, r);
+        }
+    }
+}
+
+// The secret equality from the preprocessed correlations counted by
+// `Batch::eq_const`.
+#[test]
+fn eq_secret_preprocessed() {
+    let pairs = pairs();
+    let pairs_sh: Vec<(u64, [FE; 2])> = pairs.iter().map(|&(r, x)| (r, share_pair(x))).collect();
+    let mut batch = Batch::new();
+    batch.eq_const(GateKind::Ot, pairs.len());
+    let [pre_0, pre_1] = batch.deal(&mut rand::thread_rng());
+    let (w_0, w_1) = run_with_dealers([Box::new(pre_0), Box::new(pre_1)], |p| {
+        pairs_sh
+            .iter()
+            .map(|(r, x_sh)| eq_secret(p, x_sh[p.id], if p.id == 0 { FE::new(*r) } else { FE::zero() }))
+            .collect::<Result<Vec<_>>>()
+    });
+    for (((r, x), w_0), w_1) in pairs.iter().zip(w_0.unwrap()).zip(w_1.unwrap()) {
+        assert_eq!(w_0 ^ w_1 != 0, x.value() == *r, "EQ Secret (preprocessed): {} == {}", x, r);
+    }
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+
+use common::run;
+
+// Checks the shared gates against their plaintext truth tables, for every
+// input and both values of P0's random shares.
+fn check_gates(kind: GateKind) {
+    let bits = [false, true];
+    for &x in &bits {
+        for &y in &bits {
+            for &x0 in &bits {
+                for &y0 in &bits {
+                    let x_sh = [x0, x ^ x0];
+                    let y_sh = [y0, y ^ y0];
+                    let ((a0, o0, e0, n0), (a1, o1, e1, n1)) = run(kind, |p| {
+                        let (x, y) = (x_sh[p.id], y_sh[p.id]);
+                        Ok((
+                            gates::and_gate(p, x, y)?,
+                            gates::or_gate(p, x, y)?,
+                            gates::xor_gate(x, y),
+                            gates::not_gate(p, x),
+                        ))
+                    });
+                    assert_eq!(a0 ^ a1, x & y, "AND: {} & {}", x, y);
+                    assert_eq!(o0 ^ o1, x | y, "OR: {} | {}", x, y);
+                    assert_eq!(e0 ^ e1, x ^ y, "XOR: {} ^ {}", x, y);
+                    assert_eq!(n0 ^ n1, !x, "NOT: ~{}", x);
+                    for &s in &bits {
+                        for &s0 in &bits {
+                            let s_sh = [s0, s ^ s0];
+                            let (m0, m1) = run(kind, |p| {
+                                gates::mux_gate(p, s_sh[p.id], x_sh[p.id], y_sh[p.id])
+                            });
+                            assert_eq!(m0 ^ m1, if s { y } else { x }, "MUX: {} ? {} : {}", s, y, x);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ot_gates() {
+    check_gates(GateKind::Ot);
+}
+
+#[test]
+fn triple_gates() {
+    check_gates(GateKind::Triple);
+}
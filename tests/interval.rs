@@ -0,0 +1,116 @@
+mod common;
+
+use rand::Rng;
+
+use rabbit_mpc_comparisons::fastfield::PRIME_ORDER;
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{interval, interval_field, lt_const_field, Result, Share, FE};
+
+use common::{run, share_pair, ITER, K};
+
+// Random ranges and both ends of the field, against random x and the ends
+// of the field.
+fn field_cases() -> Vec<(u64, u64, FE)> {
+    let p = PRIME_ORDER;
+    let mut cases: Vec<(u64, u64, FE)> = (0..ITER)
+        .map(|_| {
+            let (a, b) = (FE::random().value(), FE::random().value());
+            (a.min(b), a.max(b), FE::random())
+        })
+        .collect();
+    for (lo, hi) in [(0, p), (0, 1), (p - 1, p), (1, p - 1), (0, 0)] {
+        for x in [0, 1, p - 2, p - 1] {
+            cases.push((lo, hi, FE::new(x)));
+        }
+    }
+    cases
+}
+
+// The interval test over the whole K-bit domain.
+#[test]
+fn interval_exhaustive() {
+    let mut rng = rand::thread_rng();
+    let mut ranges: Vec<(u64, u64)> = (0..4)
+        .map(|_| {
+            let (a, b) = (rng.gen_range(0..=(1 << K)), rng.gen_range(0..=(1 << K)));
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    ranges.extend([(0, 1 << K), (0, 0), (1, 1), (0, 1), ((1 << K) - 1, 1 << K)]);
+    let x_sh: Vec<[FE; 2]> = (0..1u64 << K).map(|x| share_pair(FE::new(x))).collect();
+    for &(lo, hi) in &ranges {
+        let (w_0, w_1) = run(GateKind::Ot, |p| {
+            x_sh.iter().map(|x_sh| interval::<K>(p, lo, hi, x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (x, (w_0, w_1)) in w_0.iter().zip(&w_1).enumerate() {
+            let x = x as u64;
+            assert_eq!(w_0 ^ w_1 != 0, lo <= x && x < hi, "Interval: {} <= {} < {}", lo, x, hi);
+        }
+    }
+}
+
+// The interval test over the whole field.
+#[test]
+fn interval_field_ends() {
+    let cases = field_cases();
+    let cases_sh: Vec<(u64, u64, [FE; 2])> = cases.iter().map(|&(lo, hi, x)| (lo, hi, share_pair(x))).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        cases_sh.iter().map(|(lo, hi, x_sh)| interval_field(p, *lo, *hi, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (((lo, hi, x), w_0), w_1) in cases.iter().zip(w_0).zip(w_1) {
+        let x = x.value();
+        assert_eq!(w_0 ^ w_1 != 0, *lo <= x && x < *hi, "Interval (field): {} <= {} < {}", lo, x, hi);
+    }
+}
+
+// One interval test must cost less than the two LTConst it replaces,
+// [L <= x < U] = [x <= U - 1] ^ [x <= L - 1] for L >= 1.
+#[test]
+fn interval_saves_an_lt_const() {
+    let cases: Vec<(u64, u64, [FE; 2])> =
+        field_cases().into_iter().filter(|(lo, _, _)| *lo >= 1).map(|(lo, hi, x)| (lo, hi, share_pair(x))).collect();
+    let (cost_0, cost_1) = run(GateKind::Ot, |p| {
+        for (lo, hi, x_sh) in &cases {
+            interval_field(p, *lo, *hi, x_sh[p.id])?;
+            lt_const_field(p, hi - 1, x_sh[p.id])?;
+            lt_const_field(p, lo - 1, x_sh[p.id])?;
+        }
+        Ok((p.stats.op("interval_field").cost, p.stats.op("lt_const_field").cost))
+    });
+    for (id, (one, two)) in [cost_0, cost_1].into_iter().enumerate() {
+        assert!(one.bytes < two.bytes, "P{}: interval sends {} bytes, two LT Const {}", id, one.bytes, two.bytes);
+        assert!(one.rounds <= two.rounds, "P{}: interval takes {} rounds, two LT Const {}", id, one.rounds, two.rounds);
+    }
+}
+
+// The interval test at width W, on random ranges and inputs and at both ends
+// of the domain.
+fn check_width<const W: usize>() {
+    let mut rng = rand::thread_rng();
+    let m = 1u64 << W;
+    let mut cases: Vec<(u64, u64, u64)> = (0..ITER)
+        .map(|_| {
+            let (a, b) = (rng.gen_range(0..=m), rng.gen_range(0..=m));
+            (a.min(b), a.max(b), rng.gen_range(0..m))
+        })
+        .collect();
+    for (lo, hi) in [(0, m), (0, 1), (m - 1, m), (1, m - 1), (0, 0)] {
+        for x in [0, 1, m - 2, m - 1] {
+            cases.push((lo, hi, x));
+        }
+    }
+    let x_sh: Vec<[FE; 2]> = cases.iter().map(|&(_, _, x)| share_pair(FE::new(x))).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        cases.iter().zip(&x_sh).map(|(&(lo, hi, _), x_sh)| interval::<W>(p, lo, hi, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for (((lo, hi, x), w_0), w_1) in cases.iter().zip(w_0).zip(w_1) {
+        assert_eq!(w_0 ^ w_1 != 0, lo <= x && x < hi, "Interval ({} bits): {} <= {} < {}", W, lo, x, hi);
+    }
+}
+
+#[test]
+fn interval_wide() {
+    check_width::<16>();
+    check_width::<32>();
+    check_width::<40>();
+}
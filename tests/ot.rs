@@ -0,0 +1,140 @@
+mod common;
+
+use std::sync::Mutex;
+
+use bitvec::field::BitField;
+use bitvec::vec::BitVec;
+use rand::Rng;
+
+use rabbit_mpc_comparisons::dealer::NoDealer;
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+use rabbit_mpc_comparisons::ot::{self, OtSource};
+use rabbit_mpc_comparisons::{lt_bits, lt_const_field, MemoryChannel, OtPool, Party, Result, Share, FE};
+
+use common::{run, run_ot, share_bits, share_pair, ITER, K};
+
+// OTs of each direction extended at once by the OT pool, and held at most
+const POOL_BATCH: usize = 1 << 12;
+const POOL_CAPACITY: usize = 1 << 15;
+
+// Random OTs from the base OT.
+#[test]
+fn base_ot() {
+    const N_OT: usize = 128;
+    let choices: Vec<bool> = (0..N_OT).map(|_| rand::random()).collect();
+    let ((keys, _), (_, k_c)) = run(GateKind::Ot, |p| {
+        if p.id == 0 {
+            Ok((ot::random_ot_send(p, N_OT)?, Vec::new()))
+        } else {
+            Ok((Vec::new(), ot::random_ot_recv(p, &choices)?))
+        }
+    });
+    for ((k, k_c), &c) in keys.iter().zip(&k_c).zip(&choices) {
+        assert_eq!(*k_c, k[c as usize], "base OT: wrong key received");
+        assert_ne!(*k_c, k[!c as usize], "base OT: both keys received");
+    }
+}
+
+// The OT-based gates and LTBits on top of the base OTs.
+#[test]
+fn gates_on_base_ot() {
+    let bits = [false, true];
+    for &x in &bits {
+        for &y in &bits {
+            let x_sh = [false, x];
+            let y_sh = [y, false];
+            let ((a_0, o_0), (a_1, o_1)) = run_ot(OtSource::BaseOt, |p| {
+                let (x, y) = (x_sh[p.id], y_sh[p.id]);
+                Ok((gates::and_gate(p, x, y)?, gates::or_gate(p, x, y)?))
+            });
+            assert_eq!(a_0 ^ a_1, x & y, "AND (base OT): {} & {}", x, y);
+            assert_eq!(o_0 ^ o_1, x | y, "OR (base OT): {} | {}", x, y);
+        }
+    }
+    let mut rng = rand::thread_rng();
+    let const_r: u64 = rng.gen_range(0..(1 << K));
+    let x: u64 = rng.gen_range(0..(1 << K));
+    let x_sh = share_bits(x, K);
+    let (w_0, w_1) = run_ot(OtSource::BaseOt, |p| lt_bits::<K>(p, const_r, &x_sh[p.id]));
+    assert_eq!(w_0 ^ w_1 != 0, const_r <= x, "LT Bits (base OT): {} <= {}", const_r, x);
+}
+
+// Random OTs from the IKNP extension, in two batches to also cover the
+// continuation of the PRG streams.
+#[test]
+fn iknp() {
+    const N_OT: usize = 1000;
+    let choices: Vec<bool> = (0..N_OT).map(|_| rand::random()).collect();
+    let ((keys, _), (_, k_c)) = run(GateKind::Ot, |p| {
+        if p.id == 0 {
+            let mut ext = ot::IknpSender::setup(p)?;
+            let mut keys = ext.extend(p, N_OT / 2)?;
+            keys.extend(ext.extend(p, N_OT / 2)?);
+            Ok((keys, Vec::new()))
+        } else {
+            let mut ext = ot::IknpReceiver::setup(p)?;
+            let mut k_c = ext.extend(p, &choices[..N_OT / 2])?;
+            k_c.extend(ext.extend(p, &choices[N_OT / 2..])?);
+            Ok((Vec::new(), k_c))
+        }
+    });
+    for ((k, k_c), &c) in keys.iter().zip(&k_c).zip(&choices) {
+        assert_eq!(*k_c, k[c as usize], "IKNP: wrong key received");
+        assert_ne!(*k_c, k[!c as usize], "IKNP: both keys received");
+    }
+}
+
+// LTBits with OTs from the dealer and from the OT extension.
+#[test]
+fn lt_bits_on_iknp() {
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<(u64, [BitVec<u8>; 2])> = (0..ITER)
+        .map(|_| (rng.gen_range(0..(1 << K)), share_bits(rng.gen_range(0..(1 << K)), K)))
+        .collect();
+    for source in [OtSource::Dealer, OtSource::Iknp] {
+        let (w_0, w_1) = run_ot(source, |p| {
+            inputs.iter().map(|(r, x_sh)| lt_bits::<K>(p, *r, &x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for (((r, x_sh), w_0), w_1) in inputs.iter().zip(w_0).zip(w_1) {
+            let x = gates::reconstruct_shares(&x_sh[0], &x_sh[1]).load_le::<u64>();
+            assert_eq!(w_0 ^ w_1 != 0, *r <= x, "LT Bits ({:?} OTs): {} <= {}", source, r, x);
+        }
+    }
+}
+
+// LTConst with OTs from a background OT pool.
+#[test]
+fn pool() {
+    let (chan_0, chan_1) = MemoryChannel::pair();
+    let pool_chans = [Mutex::new(Some(chan_0)), Mutex::new(Some(chan_1))];
+    let inputs: Vec<(u64, [FE; 2])> = (0..ITER).map(|_| (FE::random().value(), share_pair(FE::random()))).collect();
+    let (w_0, w_1) = run_ot(OtSource::Pool, |p| {
+        let chan = pool_chans[p.id].lock().unwrap().take().expect("one pool channel per party");
+        let worker = Party::new(p.id, Box::new(chan), Box::new(NoDealer));
+        p.ot_pool = Some(OtPool::spawn(worker, POOL_BATCH, POOL_CAPACITY));
+        let w = inputs.iter().map(|(r, x_sh)| lt_const_field(p, *r, x_sh[p.id])).collect::<Result<Vec<_>>>()?;
+        let pool = p.ot_pool.take().expect("pool installed above");
+        assert!(pool.consumed() > 0, "the comparisons must draw OTs from the pool");
+        pool.stop()?;
+        Ok(w)
+    });
+    for (((r, x_sh), w_0), w_1) in inputs.iter().zip(w_0).zip(w_1) {
+        let x = (x_sh[0] + x_sh[1]).value();
+        assert_eq!(w_0 ^ w_1 != 0, x <= *r, "LT Const (OT pool): {} <= {}", x, r);
+    }
+}
+
+// Correlated OTs over FE.
+#[test]
+fn correlated_ot() {
+    const N_COT: usize = 1000;
+    let deltas: Vec<FE> = (0..N_COT).map(|_| FE::random()).collect();
+    let choices: Vec<bool> = (0..N_COT).map(|_| rand::random()).collect();
+    let (s, t) = run(GateKind::Ot, |p| {
+        if p.id == 0 { ot::cot_send(p, &deltas) } else { ot::cot_recv(p, &choices) }
+    });
+    for (((s, t), delta), &b) in s.iter().zip(&t).zip(&deltas).zip(&choices) {
+        let expected = if b { *s + *delta } else { *s };
+        assert_eq!(*t, expected, "correlated OT: wrong message received");
+    }
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use std::env;
+use std::process;
+
+use rabbit_mpc_comparisons::gates::GateKind;
+use rabbit_mpc_comparisons::{lt_const, lt_const_field, run_with_dealers, Batch, Dealer, Error, Preprocessing, Result, Share, FE};
+
+use common::share_pair;
+
+// Runs field comparisons on preprocessing written to and read back from a
+// file, until the preprocessed material runs out.
+#[test]
+fn preprocessing_from_file() {
+    const N: usize = 3;
+    let mut batch = Batch::new();
+    batch.lt_const_field(GateKind::Ot, N);
+    let prep = env::temp_dir().join(format!("rabbit-prep-{}", process::id()));
+    // Each party's half goes through its own file.
+    let reload = |pre: &Preprocessing| -> Box<dyn Dealer> {
+        let path = prep.with_extension(format!("p{}", pre.party()));
+        pre.write(&path).expect("cannot write the preprocessing");
+        let pre = Preprocessing::read(&path).expect("cannot read the preprocessing");
+        let _ = std::fs::remove_file(&path);
+        Box::new(pre)
+    };
+    let [pre_0, pre_1] = batch.deal(&mut rand::thread_rng());
+    let dealers = [reload(&pre_0), reload(&pre_1)];
+
+    let x: Vec<FE> = (0..=N).map(|_| FE::random()).collect();
+    let const_r = FE::random().value();
+    let x_sh: Vec<[FE; 2]> = x.iter().map(|&x| share_pair(x)).collect();
+    let (out_0, out_1) = run_with_dealers(dealers, |p| {
+        x_sh.iter().map(|x_sh| lt_const_field(p, const_r, x_sh[p.id])).collect::<Vec<_>>()
+    });
+    for i in 0..N {
+        let lt = out_0[i].as_ref().unwrap() ^ out_1[i].as_ref().unwrap();
+        assert_eq!(lt != 0, x[i].value() <= const_r, "LT Const (preprocessed)");
+    }
+    assert!(matches!(out_0[N], Err(Error::Exhausted(_))), "P0 must run out of preprocessing");
+    assert!(matches!(out_1[N], Err(Error::Exhausted(_))), "P1 must run out of preprocessing");
+}
+
+// `Batch::lt_const` must deal what LTConst uses at every width, the K-bit
+// edaBits of the masked opening as well as the field edaBits above
+// MASKED_MAX_K.
+#[test]
+fn lt_const_batch_widths() {
+    fn check<const W: usize>() {
+        const N: usize = 3;
+        let mut batch = Batch::new();
+        batch.lt_const(W, GateKind::Ot, N);
+        let [pre_0, pre_1] = batch.deal(&mut rand::thread_rng());
+        let x: Vec<u64> = (0..N as u64).collect();
+        let x_sh: Vec<[FE; 2]> = x.iter().map(|&x| share_pair(FE::new(x))).collect();
+        let (out_0, out_1) = run_with_dealers([Box::new(pre_0), Box::new(pre_1)], |p| {
+            x_sh.iter().map(|x_sh| lt_const::<W>(p, 1, x_sh[p.id])).collect::<Result<Vec<_>>>()
+        });
+        for ((x, w_0), w_1) in x.iter().zip(out_0.expect("batch too small")).zip(out_1.expect("batch too small")) {
+            assert_eq!(w_0 ^ w_1 != 0, *x <= 1, "LT Const (preprocessed, {} bits): {} <= 1", W, x);
+        }
+    }
+    check::<8>();
+    check::<16>();
+    check::<40>();
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use bitvec::vec::BitVec;
+use rand::Rng;
+
+use rabbit_mpc_comparisons::gates::{self, GateKind};
+use rabbit_mpc_comparisons::signed::{self, FE_SIGNED_MAX};
+use rabbit_mpc_comparisons::{compare_bits_signed, compare_const_field_signed, compare_const_ring_signed, compare_const_signed};
+use rabbit_mpc_comparisons::{lt_secret_signed, sign_bits, sign_field, CmpOp, Group, Result, FE, Z2k};
+
+use common::{run, share_bits, share_pair, ITER};
+
+const CMP_K: usize = 4;
+
+// The signed comparisons exhaustively on the small domain
+// [-2^(CMP_K-1), 2^(CMP_K-1)) against every R one beyond it and the ends of
+// i64, on bits, over FE and over the ring; and the sign bits.
+#[test]
+fn compare_signed_exhaustive() {
+    let half = 1i64 << (CMP_K - 1);
+    let cases: Vec<(CmpOp, i64, i64)> = CmpOp::ALL
+        .iter()
+        .flat_map(|&op| (-half - 1..=half).chain([i64::MIN, i64::MAX]).flat_map(move |r| (-half..half).map(move |x| (op, r, x))))
+        .collect();
+    let bits_sh: Vec<[BitVec<u8>; 2]> = (-half..half).map(|x| share_bits(x as u64, CMP_K)).collect();
+    let fe_sh: Vec<[FE; 2]> = (-half..half).map(|x| share_pair(signed::fe_from_signed(x))).collect();
+    let ring_sh: Vec<[Z2k<CMP_K>; 2]> = (-half..half).map(|x| share_pair(Z2k::<CMP_K>::new(x as u64))).collect();
+    let ((w_bits, w_fe, w_ring), (v_bits, v_fe, v_ring)) = run(GateKind::Ot, |p| {
+        let mut w = (Vec::new(), Vec::new(), Vec::new());
+        for &(op, r, x) in &cases {
+            let i = (x + half) as usize;
+            w.0.push(compare_bits_signed::<CMP_K>(p, op, r, &bits_sh[i][p.id])?);
+            w.1.push(compare_const_signed::<CMP_K>(p, op, r, fe_sh[i][p.id])?);
+            w.2.push(compare_const_ring_signed::<CMP_K>(p, op, r, ring_sh[i][p.id])?);
+        }
+        Ok(w)
+    });
+    for (i, &(op, r, x)) in cases.iter().enumerate() {
+        let expected = op.eval(x, r);
+        assert_eq!(w_bits[i] ^ v_bits[i] != 0, expected, "Signed compare (bits): {} {:?} {}", x, op, r);
+        assert_eq!(w_fe[i] ^ v_fe[i] != 0, expected, "Signed compare (FE): {} {:?} {}", x, op, r);
+        assert_eq!(w_ring[i] ^ v_ring[i] != 0, expected, "Signed compare (ring): {} {:?} {}", x, op, r);
+    }
+    for (i, x) in (-half..half).enumerate() {
+        let bits = gates::reconstruct_shares(&bits_sh[i][0], &bits_sh[i][1]);
+        assert_eq!(bits, signed::bits_from_signed(x, CMP_K), "two's complement: {}", x);
+        assert_eq!(signed::bits_to_signed(&bits), x, "two's complement: {}", x);
+        assert_eq!(sign_bits::<CMP_K>(&bits_sh[i][0]) ^ sign_bits::<CMP_K>(&bits_sh[i][1]) != 0, x < 0, "Sign (bits): {}", x);
+    }
+}
+
+// The signed comparisons and the sign at the ends of the centred FE range.
+#[test]
+fn compare_signed_field_ends() {
+    let h = FE_SIGNED_MAX;
+    let ends = [-h, -h + 1, -1, 0, 1, h - 1, h];
+    let cases: Vec<(CmpOp, i64, i64)> = CmpOp::ALL
+        .iter()
+        .flat_map(|&op| ends.into_iter().chain([-h - 1, h + 1, i64::MIN, i64::MAX]).flat_map(move |r| ends.map(move |x| (op, r, x))))
+        .collect();
+    let x_sh: Vec<[FE; 2]> = cases.iter().map(|&(_, _, x)| share_pair(signed::fe_from_signed(x))).collect();
+    let ((w_0, s_0), (w_1, s_1)) = run(GateKind::Ot, |p| {
+        let w = cases.iter().zip(&x_sh).map(|(&(op, r, _), x_sh)| compare_const_field_signed(p, op, r, x_sh[p.id])).collect::<Result<Vec<_>>>()?;
+        let s = ends.iter().map(|&x| sign_field(p, if p.id == 0 { signed::fe_from_signed(x) } else { FE::zero() })).collect::<Result<Vec<_>>>()?;
+        Ok((w, s))
+    });
+    for ((&(op, r, x), w_0), w_1) in cases.iter().zip(w_0).zip(w_1) {
+        assert_eq!(w_0 ^ w_1 != 0, op.eval(x, r), "Signed compare (field): {} {:?} {}", x, op, r);
+    }
+    for ((&x, s_0), s_1) in ends.iter().zip(s_0).zip(s_1) {
+        assert_eq!(signed::fe_to_signed(signed::fe_from_signed(x)), x, "centred FE: {}", x);
+        assert_eq!(s_0 ^ s_1 != 0, x < 0, "Sign (field): {}", x);
+    }
+}
+
+// The signed comparisons on FE at width W, around zero and at both ends of
+// the signed range.
+fn check_width<const W: usize>() {
+    let half = 1i64 << (W - 1);
+    let ends = [-half, -half + 1, -1, 0, 1, half - 2, half - 1];
+    let cases: Vec<(CmpOp, i64, i64)> = CmpOp::ALL
+        .iter()
+        .flat_map(|&op| ends.into_iter().chain([-half - 1, half]).flat_map(move |r| ends.map(move |x| (op, r, x))))
+        .collect();
+    let x_sh: Vec<[FE; 2]> = cases.iter().map(|&(_, _, x)| share_pair(signed::fe_from_signed(x))).collect();
+    let (w_0, w_1) = run(GateKind::Ot, |p| {
+        cases.iter().zip(&x_sh).map(|(&(op, r, _), x_sh)| compare_const_signed::<W>(p, op, r, x_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for ((&(op, r, x), w_0), w_1) in cases.iter().zip(w_0).zip(w_1) {
+        assert_eq!(w_0 ^ w_1 != 0, op.eval(x, r), "Signed compare (FE, {} bits): {} {:?} {}", W, x, op, r);
+    }
+}
+
+#[test]
+fn compare_signed_wide() {
+    check_width::<16>();
+    check_width::<32>();
+    check_width::<40>();
+}
+
+#[test]
+#[should_panic(expected = "out of the signed FE range")]
+fn fe_from_signed_rejects_i64_min() {
+    signed::fe_from_signed(i64::MIN);
+}
+
+// The signed secret comparison, on values that keep x - y in range.
+#[test]
+fn lt_secret_signed_half_range() {
+    let h = FE_SIGNED_MAX;
+    let mut rng = rand::thread_rng();
+    let mut pairs: Vec<(i64, i64)> = (0..ITER).map(|_| (rng.gen_range(-h / 2..=h / 2), rng.gen_range(-h / 2..=h / 2))).collect();
+    pairs.extend([(-h / 2, h / 2), (h / 2, -h / 2), (-1, 0), (0, -1), (5, 5)]);
+    let pairs_sh: Vec<([FE; 2], [FE; 2])> =
+        pairs.iter().map(|&(x, y)| (share_pair(signed::fe_from_signed(x)), share_pair(signed::fe_from_signed(y)))).collect();
+    let (c_0, c_1) = run(GateKind::Ot, |p| {
+        pairs_sh.iter().map(|(x_sh, y_sh)| lt_secret_signed(p, x_sh[p.id], y_sh[p.id])).collect::<Result<Vec<_>>>()
+    });
+    for ((&(x, y), c_0), c_1) in pairs.iter().zip(c_0).zip(c_1) {
+        assert_eq!(c_0 ^ c_1 != 0, x < y, "LT Secret (signed): {} < {}", x, y);
+    }
+}